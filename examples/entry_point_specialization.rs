@@ -1,4 +1,4 @@
-use slang::{GlobalSession, SessionDesc, SpecializeArgument};
+use slang::{GlobalSession, SessionDesc, SpecializeArgument, reflect::SpecializationParameter};
 
 fn main() {
     let global = GlobalSession::new().unwrap();
@@ -15,26 +15,38 @@ fn main() {
         .load_module("examples/entry_point_specialization")
         .unwrap();
 
-    let f32_ty = module
-        .layout(0)
-        .unwrap()
-        .find_type_by_name("float")
-        .unwrap();
-
     let entry_point = module.find_entry_point_by_name("main").unwrap();
 
     assert_eq!(entry_point.specialization_param_count(), 2);
 
-    let specialized_entry_point = entry_point
-        .specialize(&[
-            SpecializeArgument::Expr("true"),
-            SpecializeArgument::Type(f32_ty),
-        ])
-        .expect("cannot specialize entry point");
+    let unspecialized = session
+        .create_composite_component_type(&[module.into(), entry_point.into()])
+        .unwrap();
 
-    let composed = session
-        .create_composite_component_type(&[module.into(), specialized_entry_point.into()])
-        .unwrap()
+    // Discover what the program needs instead of hardcoding it.
+    let layout = unspecialized.layout(0).unwrap();
+    let f32_ty = layout.find_type_by_name("float").unwrap();
+    let arguments: Vec<_> = layout
+        .specialization_parameters()
+        .map(|param| {
+            println!(
+                "{:?}: {} constraints",
+                param.name(),
+                param.constraints().len()
+            );
+            match param {
+                SpecializationParameter::EntryPointValue(..) => {
+                    assert_eq!(param.value_type().and_then(|ty| ty.name()), Some("bool"));
+                    SpecializeArgument::Expr("true")
+                }
+                _ => SpecializeArgument::Type(f32_ty),
+            }
+        })
+        .collect();
+
+    let composed = unspecialized
+        .specialize(&arguments)
+        .expect("cannot specialize entry point")
         .link()
         .unwrap();
 
@@ -0,0 +1,70 @@
+use slang::{GlobalSession, SessionDesc, reflect::ReflectionSnapshot};
+
+fn main() {
+    let global = GlobalSession::new().unwrap();
+
+    let targets = [slang::TargetDesc::default()
+        .format(slang::CompileTarget::Spirv)
+        .profile(global.find_profile("spirv_1_5"))];
+
+    let session = global
+        .create_session(&SessionDesc::default().targets(&targets))
+        .unwrap();
+
+    let module = session
+        .load_module_from_source_string(
+            "reflection_json",
+            "examples/reflection_json.slang",
+            include_str!("reflection_json.slang"),
+        )
+        .unwrap();
+
+    let entry_point = module.find_entry_point_by_name("main").unwrap();
+
+    let program = session
+        .create_composite_component_type(&[module.into(), entry_point.into()])
+        .unwrap()
+        .link()
+        .unwrap();
+
+    let shader = program.layout(0).unwrap();
+
+    let json = shader.to_json_value().unwrap();
+    println!("{}", serde_json::to_string_pretty(&json).unwrap());
+
+    // Cross check the compiler's JSON against our own traversal.
+    let snapshot = ReflectionSnapshot::new(shader);
+
+    let json_entry_points: Vec<_> = json["entryPoints"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["name"].as_str().unwrap())
+        .collect();
+    let entry_points: Vec<_> = snapshot
+        .entry_points
+        .iter()
+        .map(|e| e.name.as_deref().unwrap())
+        .collect();
+    assert_eq!(json_entry_points, entry_points);
+
+    let json_parameters: Vec<_> = json["parameters"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|p| p["name"].as_str().unwrap())
+        .collect();
+    println!("Global parameters: {json_parameters:?}");
+    assert_eq!(json_parameters, ["constants", "image", "linear_sampler"]);
+
+    let thread_group_size = &json["entryPoints"][0]["threadGroupSize"];
+    assert_eq!(
+        thread_group_size
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|v| v.as_u64().unwrap())
+            .collect::<Vec<_>>(),
+        snapshot.entry_points[0].compute_thread_group_size
+    );
+}
@@ -108,6 +108,7 @@ fn main() {
         println!("{:?}", reflect.bindless_space_index());
         let var = reflect.global_params_var_layout().unwrap();
         print_var_layout(var);
+        print_binding_ranges(var.type_layout().unwrap());
         validate_shader(reflect);
 
         std::fs::create_dir_all("examples/output").unwrap();
@@ -209,3 +210,15 @@ fn print_var_layout(var: &slang::reflect::VariableLayout) {
         }
     }
 }
+
+fn print_binding_ranges(layout: &slang::reflect::TypeLayout) {
+    for set in layout.descriptor_sets() {
+        println!("{set:?}");
+    }
+    for range in layout.binding_ranges() {
+        println!("{range:?}");
+    }
+    for range in layout.sub_object_ranges() {
+        println!("{range:?}");
+    }
+}
@@ -8,6 +8,16 @@ pub enum Error {
     Code(sys::SlangResult),
     #[error("Failed with diagnotics: {0:?}")]
     Blob(Blob),
+    #[error("Unknown capability: {0}")]
+    UnknownCapability(String),
+    #[error("Invalid digest length: {0}")]
+    InvalidDigestLength(usize),
+    #[cfg(feature = "serde")]
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+    #[cfg(feature = "serde")]
+    #[error(transparent)]
+    Postcard(#[from] postcard::Error),
     #[error("Unknown")]
     Unknown,
 }
@@ -13,12 +13,18 @@ pub use error::*;
 #[cfg(feature = "preprocess")]
 pub use preprocess::{FileType, get_file_type, preprocess};
 pub use sys::{
+    SlangArchiveType as ArchiveType, SlangBindingType as BindingType,
     SlangCompileTarget as CompileTarget, SlangDebugInfoLevel as DebugInfoLevel,
-    SlangFloatingPointMode as FloatingPointMode, SlangLineDirectiveMode as LineDirectiveMode,
-    SlangMatrixLayoutMode as MatrixLayoutMode, SlangOptimizationLevel as OptimizationLevel,
-    SlangParameterCategory as ParameterCategory, SlangResourceAccess as ResourceAccess,
-    SlangResourceShape as ResourceShape, SlangScalarType as ScalarType,
-    SlangSourceLanguage as SourceLanguage, SlangStage as Stage, SlangUUID as Uuid,
+    SlangDeclKind as DeclKind, SlangFloatingPointMode as FloatingPointMode,
+    SlangImageFormat as ImageFormat, SlangLineDirectiveMode as LineDirectiveMode,
+    SlangMatrixLayoutMode as MatrixLayoutMode, SlangModifierID as ModifierId,
+    SlangOptimizationLevel as OptimizationLevel, SlangParameterCategory as ParameterCategory,
+    SlangPassThrough as PassThrough, SlangPathType as PathType,
+    SlangResourceAccess as ResourceAccess, SlangResourceShape as ResourceShape,
+    SlangScalarType as ScalarType, SlangSourceLanguage as SourceLanguage, SlangStage as Stage,
+    SlangTypeKind as TypeKind, SlangUUID as Uuid, slang_BuiltinModuleName as BuiltinModuleName,
+    slang_CompilerOptionName as CompilerOptionName, slang_ContainerType as ContainerType,
+    slang_LayoutRules as LayoutRules,
 };
 pub use types::*;
 
@@ -29,12 +35,21 @@ use core::{
     ops::Deref,
     ptr::{NonNull, null_mut},
     str::Utf8Error,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
 };
 use std::{
     ffi::{CStr, CString, c_void},
-    path::Path,
+    path::{Path, PathBuf},
+    sync::{Arc, RwLock},
 };
 
+/// Build tag of the Slang library this crate is linked against, e.g. `2025.6.1`.
+pub fn build_tag() -> &'static str {
+    let tag = unsafe { sys::spGetBuildTagString() };
+    unsafe { CStr::from_ptr(tag) }.to_str().unwrap_or_default()
+}
+
 /// # Safety
 ///
 /// Underlying data must contains a vtable
@@ -88,6 +103,7 @@ macro_rules! vcall_maybe_diagnostics {
 		{
 			let mut out_diagnostics = null_mut();
 			let result = vcall!($self, $method($($args),* , &mut out_diagnostics));
+			report_diagnostics($self, out_diagnostics);
 			if result >= 0 {
 				Ok(())
 			} else {
@@ -104,6 +120,27 @@ macro_rules! vcall_maybe_diagnostics {
 	};
 }
 
+/// Enter a span for a compile step of `$self`, a [`ComponentType`]. Evaluates to the span and
+/// the timings at its start, which `ComponentType::record_timings` turns into the time taken by
+/// the step once it is done. Compiles running at the same time on one global session are
+/// counted in each other's timings, see [`CompileTimings`].
+#[cfg(feature = "trace")]
+macro_rules! compile_span {
+    ($self:expr, $name:literal $(, $field:ident)*) => {
+        (
+            tracing::info_span!(
+                $name,
+                $($field,)*
+                build_tag = build_tag(),
+                total_time = tracing::field::Empty,
+                downstream_time = tracing::field::Empty
+            )
+            .entered(),
+            $self.session().global_session().elapsed_time(),
+        )
+    };
+}
+
 const fn uuid(data1: u32, data2: u16, data3: u16, data4: [u8; 8]) -> Uuid {
     Uuid {
         data1,
@@ -249,6 +286,60 @@ impl FileSystem {
     pub fn is_interface_compatible(uuid: &Uuid) -> bool {
         Unknown::matches(uuid) || Castable::matches(uuid) || FileSystem::matches(uuid)
     }
+
+    pub fn load_file(&self, path: &str) -> Result<Blob> {
+        let path = CString::new(path).map_err(|_| Error::Unknown)?;
+        let mut blob = null_mut();
+        vcall_maybe!(self, loadFile(path.as_ptr(), &mut blob))?;
+        Unknown::new_with_ref(blob).map(Blob).ok_or(Error::Unknown)
+    }
+
+    /// Names and types of the entries directly inside the directory at `path`. Only supported
+    /// by file systems created by Slang, such as [`ComponentType::result_as_file_system`].
+    pub fn enumerate_path_contents(&self, path: &str) -> Result<Vec<(PathType, String)>> {
+        unsafe extern "C" fn push_entry(
+            path_type: PathType,
+            name: *const core::ffi::c_char,
+            user_data: *mut c_void,
+        ) {
+            let entries = unsafe { &mut *(user_data as *mut Vec<(PathType, String)>) };
+            let name = unsafe { CStr::from_ptr(name) }.to_string_lossy();
+            entries.push((path_type, name.into_owned()));
+        }
+
+        let file_system = Castable(self.0.clone())
+            .cast_as::<FileSystemExt>()
+            .ok_or(Error::Unknown)?;
+        let path = CString::new(path).map_err(|_| Error::Unknown)?;
+        let mut entries = Vec::new();
+        vcall_maybe!(
+            file_system,
+            enumeratePathContents(
+                path.as_ptr(),
+                Some(push_entry),
+                &mut entries as *mut Vec<_> as *mut c_void
+            )
+        )?;
+        Ok(entries)
+    }
+}
+
+#[repr(transparent)]
+#[derive(Clone)]
+struct FileSystemExt(Unknown);
+
+unsafe impl Interface for FileSystemExt {
+    type Vtable = sys::ISlangFileSystemExt_vtable;
+    const UUID: Uuid = uuid(
+        0x5fb632d2,
+        0x979d,
+        0x4481,
+        [0x9f, 0xee, 0x66, 0x3c, 0x3f, 0x14, 0x49, 0xe1],
+    );
+
+    fn new(unknown: Unknown) -> Self {
+        Self(unknown)
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -263,6 +354,18 @@ impl ProfileId {
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct CapabilityId(sys::SlangCapabilityID);
+
+impl CapabilityId {
+    pub const UNKNOWN: CapabilityId = CapabilityId(sys::SlangCapabilityID::SlangCapabilityUnknown);
+
+    pub fn is_unknown(&self) -> bool {
+        self.0 == sys::SlangCapabilityID::SlangCapabilityUnknown
+    }
+}
+
 #[repr(transparent)]
 #[derive(Clone)]
 pub struct GlobalSession(Unknown);
@@ -289,6 +392,93 @@ impl GlobalSession {
         Unknown::new(ptr).map(Self).ok_or(Error::Code(err_code))
     }
 
+    /// Configure how the core module is obtained, e.g. from a cache on disk.
+    pub fn builder() -> GlobalSessionBuilder {
+        GlobalSessionBuilder::default()
+    }
+
+    /// Create a global session without the core module. One has to be compiled or loaded
+    /// before any session is created from it.
+    pub fn new_without_core_module() -> Result<Self> {
+        let mut ptr = null_mut();
+        let err_code = unsafe {
+            sys::slang_createGlobalSessionWithoutCoreModule(sys::SLANG_API_VERSION as _, &mut ptr)
+        };
+        Unknown::new(ptr).map(Self).ok_or(Error::Code(err_code))
+    }
+
+    /// The serialized core module embedded in the Slang library, if it was built with one.
+    pub fn embedded_core_module() -> Option<Blob> {
+        let blob = unsafe { sys::slang_getEmbeddedCoreModule() };
+        Unknown::new_with_ref(blob).map(Blob)
+    }
+
+    pub fn compile_core_module(&self, write_documentation: bool) -> Result<()> {
+        vcall_maybe!(
+            self,
+            compileCoreModule(core_module_flags(write_documentation))
+        )
+    }
+
+    /// Load a core module previously produced by [`GlobalSession::save_core_module`].
+    pub fn load_core_module(&self, core_module: &[u8]) -> Result<()> {
+        vcall_maybe!(
+            self,
+            loadCoreModule(core_module.as_ptr() as _, core_module.len())
+        )
+    }
+
+    pub fn save_core_module(&self, archive_type: ArchiveType) -> Result<Blob> {
+        let mut blob = null_mut();
+        vcall_maybe!(self, saveCoreModule(archive_type, &mut blob))?;
+        Unknown::new_with_ref(blob).map(Blob).ok_or(Error::Unknown)
+    }
+
+    pub fn compile_builtin_module(
+        &self,
+        module: BuiltinModuleName,
+        write_documentation: bool,
+    ) -> Result<()> {
+        vcall_maybe!(
+            self,
+            compileBuiltinModule(module, core_module_flags(write_documentation))
+        )
+    }
+
+    pub fn load_builtin_module(&self, module: BuiltinModuleName, data: &[u8]) -> Result<()> {
+        vcall_maybe!(
+            self,
+            loadBuiltinModule(module, data.as_ptr() as _, data.len())
+        )
+    }
+
+    pub fn save_builtin_module(
+        &self,
+        module: BuiltinModuleName,
+        archive_type: ArchiveType,
+    ) -> Result<Blob> {
+        let mut blob = null_mut();
+        vcall_maybe!(self, saveBuiltinModule(module, archive_type, &mut blob))?;
+        Unknown::new_with_ref(blob).map(Blob).ok_or(Error::Unknown)
+    }
+
+    /// Replace the loader used to load downstream compilers and other shared libraries.
+    pub fn set_shared_library_loader(&self, loader: impl ISlangSharedLibraryLoader + 'static) {
+        let loader: Box<dyn ISlangSharedLibraryLoader> = Box::new(loader);
+        let loader = Com::new_shared_library_loader(loader);
+        vcall!(self, setSharedLibraryLoader(loader.into_raw() as _));
+    }
+
+    /// Go back to loading shared libraries from the operating system.
+    pub fn reset_shared_library_loader(&self) {
+        vcall!(self, setSharedLibraryLoader(null_mut()));
+    }
+
+    pub fn shared_library_loader(&self) -> Option<SharedLibraryLoader> {
+        let loader = vcall!(self, getSharedLibraryLoader());
+        Unknown::new_with_ref(loader).map(SharedLibraryLoader)
+    }
+
     pub fn create_session(&self, desc: &SessionDesc) -> Result<Session> {
         let mut ptr = null_mut();
 
@@ -303,10 +493,293 @@ impl GlobalSession {
         Ok(Unknown::new(ptr).map(Session).unwrap())
     }
 
+    /// Parse `slangc` style arguments, e.g. `["-target", "spirv", "-O2"]`.
+    pub fn parse_command_line(&self, args: &[&str]) -> Result<OwnedSessionDesc> {
+        let args = args
+            .iter()
+            .map(|arg| CString::new(*arg).map_err(|_| Error::Unknown))
+            .collect::<Result<Vec<_>>>()?;
+        let argv: Vec<_> = args.iter().map(|arg| arg.as_ptr()).collect();
+
+        let mut inner = sys::slang_SessionDesc {
+            structureSize: size_of::<sys::slang_SessionDesc>(),
+            ..unsafe { core::mem::zeroed() }
+        };
+        let mut aux_allocation = null_mut();
+        vcall_maybe!(
+            self,
+            parseCommandLineArguments(
+                argv.len() as _,
+                argv.as_ptr(),
+                &mut inner,
+                &mut aux_allocation
+            )
+        )?;
+
+        Ok(OwnedSessionDesc {
+            inner,
+            _aux_allocation: Unknown::new(aux_allocation),
+        })
+    }
+
+    /// Hash of everything in `desc` that affects compilation: targets, options, search paths
+    /// and macros. Convert with [`Digest::try_from`] to use it as a cache key.
+    pub fn session_desc_digest(&self, desc: &SessionDesc) -> Result<Blob> {
+        let mut raw_desc = desc.inner;
+        let mut blob = null_mut();
+        vcall_maybe!(self, getSessionDescDigest(&mut raw_desc, &mut blob))?;
+        Unknown::new_with_ref(blob).map(Blob).ok_or(Error::Unknown)
+    }
+
     pub fn find_profile(&self, name: &str) -> ProfileId {
         let name = CString::new(name).unwrap();
         ProfileId(vcall!(self, findProfile(name.as_ptr())))
     }
+
+    /// Look up a capability such as `spirv_1_5` or `SPV_KHR_ray_query`, or `None` if Slang
+    /// doesn't know it.
+    pub fn find_capability(&self, name: &str) -> Option<CapabilityId> {
+        let name = CString::new(name).ok()?;
+        let id = CapabilityId(vcall!(self, findCapability(name.as_ptr())));
+        (!id.is_unknown()).then_some(id)
+    }
+
+    pub fn build_tag(&self) -> &str {
+        let tag = vcall!(self, getBuildTagString());
+        unsafe { CStr::from_ptr(tag) }.to_str().unwrap_or_default()
+    }
+
+    /// Time spent compiling since this global session was created, summed over all of its
+    /// sessions and threads.
+    pub fn elapsed_time(&self) -> CompileTimings {
+        let mut total = 0.0;
+        let mut downstream = 0.0;
+        vcall!(self, getCompilerElapsedTime(&mut total, &mut downstream));
+        CompileTimings {
+            total: Duration::from_secs_f64(total),
+            downstream: Duration::from_secs_f64(downstream),
+        }
+    }
+
+    /// Set the text prepended to code generated for `source_language`, e.g. the header
+    /// CPU and CUDA output is compiled against.
+    pub fn set_language_prelude(
+        &self,
+        source_language: SourceLanguage,
+        prelude: &str,
+    ) -> Result<()> {
+        let prelude = CString::new(prelude).map_err(|_| Error::Unknown)?;
+        vcall!(self, setLanguagePrelude(source_language, prelude.as_ptr()));
+        Ok(())
+    }
+
+    pub fn language_prelude(&self, source_language: SourceLanguage) -> Option<Blob> {
+        let mut prelude = null_mut();
+        vcall!(self, getLanguagePrelude(source_language, &mut prelude));
+        Unknown::new_with_ref(prelude).map(Blob)
+    }
+
+    /// Add declarations that are visible to every module compiled by sessions created
+    /// from this global session.
+    pub fn add_builtins(&self, source_path: &str, source: &str) -> Result<()> {
+        let source_path = CString::new(source_path).map_err(|_| Error::Unknown)?;
+        let source = CString::new(source).map_err(|_| Error::Unknown)?;
+        vcall!(self, addBuiltins(source_path.as_ptr(), source.as_ptr()));
+        Ok(())
+    }
+
+    /// Set the directory `pass_through` is loaded from, instead of the default search paths.
+    pub fn set_downstream_compiler_path(
+        &self,
+        pass_through: PassThrough,
+        path: &str,
+    ) -> Result<()> {
+        let path = CString::new(path).map_err(|_| Error::Unknown)?;
+        vcall!(self, setDownstreamCompilerPath(pass_through, path.as_ptr()));
+        Ok(())
+    }
+
+    /// Set the compiler used for `source_language` when it isn't compiled by Slang itself.
+    pub fn set_default_downstream_compiler(
+        &self,
+        source_language: SourceLanguage,
+        default_compiler: PassThrough,
+    ) -> Result<()> {
+        vcall_maybe!(
+            self,
+            setDefaultDownstreamCompiler(source_language, default_compiler)
+        )
+    }
+
+    pub fn default_downstream_compiler(&self, source_language: SourceLanguage) -> PassThrough {
+        vcall!(self, getDefaultDownstreamCompiler(source_language))
+    }
+
+    /// Set the compiler used to go from `source` to `target`, e.g. DXC for HLSL to DXIL.
+    pub fn set_downstream_compiler_for_transition(
+        &self,
+        source: CompileTarget,
+        target: CompileTarget,
+        compiler: PassThrough,
+    ) {
+        vcall!(
+            self,
+            setDownstreamCompilerForTransition(source, target, compiler)
+        );
+    }
+
+    /// Check that code can be generated for `target`, including any downstream compiler it
+    /// needs being available.
+    pub fn check_compile_target_support(&self, target: CompileTarget) -> Result<()> {
+        vcall_maybe!(self, checkCompileTargetSupport(target))
+    }
+
+    /// Check that `pass_through` can be loaded.
+    pub fn check_pass_through_support(&self, pass_through: PassThrough) -> Result<()> {
+        vcall_maybe!(self, checkPassThroughSupport(pass_through))
+    }
+}
+
+fn core_module_flags(write_documentation: bool) -> sys::slang_CompileCoreModuleFlags {
+    if write_documentation {
+        sys::slang_CompileCoreModuleFlag_Enum::WriteDocumentation as _
+    } else {
+        0
+    }
+}
+
+#[derive(Default)]
+enum CoreModuleSource {
+    /// Let Slang compile or load the core module the way [`GlobalSession::new`] does.
+    #[default]
+    Default,
+    Embedded,
+    Bytes(Vec<u8>),
+    Cache(PathBuf),
+}
+
+/// Creates a [`GlobalSession`], controlling where its core module comes from.
+///
+/// ```no_run
+/// let global_session = slang::GlobalSession::builder()
+///     .core_module_cache(std::env::temp_dir().join("slang-core-module.bin"))
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Default)]
+pub struct GlobalSessionBuilder {
+    core_module: CoreModuleSource,
+}
+
+impl GlobalSessionBuilder {
+    /// Load the core module embedded in the Slang library, compiling it if there is none.
+    pub fn embedded_core_module(mut self) -> Self {
+        self.core_module = CoreModuleSource::Embedded;
+        self
+    }
+
+    /// Load a core module previously produced by [`GlobalSession::save_core_module`].
+    pub fn core_module(mut self, core_module: impl Into<Vec<u8>>) -> Self {
+        self.core_module = CoreModuleSource::Bytes(core_module.into());
+        self
+    }
+
+    /// Load the core module from `path`. If it is missing or was saved by another version of
+    /// Slang, the core module is compiled and saved to `path` instead. Saving is best effort,
+    /// an unwritable `path` only costs the compile on the next build.
+    pub fn core_module_cache(mut self, path: impl Into<PathBuf>) -> Self {
+        self.core_module = CoreModuleSource::Cache(path.into());
+        self
+    }
+
+    pub fn build(self) -> Result<GlobalSession> {
+        match self.core_module {
+            CoreModuleSource::Default => GlobalSession::new(),
+            CoreModuleSource::Embedded => {
+                let global_session = GlobalSession::new_without_core_module()?;
+                match GlobalSession::embedded_core_module() {
+                    Some(blob) => global_session.load_core_module(blob.as_slice())?,
+                    None => global_session.compile_core_module(false)?,
+                }
+                Ok(global_session)
+            }
+            CoreModuleSource::Bytes(bytes) => {
+                let global_session = GlobalSession::new_without_core_module()?;
+                global_session.load_core_module(&bytes)?;
+                Ok(global_session)
+            }
+            CoreModuleSource::Cache(path) => {
+                if let Ok(bytes) = std::fs::read(&path) {
+                    let global_session = GlobalSession::new_without_core_module()?;
+                    if global_session.load_core_module(&bytes).is_ok() {
+                        return Ok(global_session);
+                    }
+                }
+
+                // A failed load can leave the session half initialized, so start over.
+                let global_session = GlobalSession::new_without_core_module()?;
+                global_session.compile_core_module(false)?;
+                // The cache is only an optimization, failing to write it still leaves a
+                // usable session.
+                if let Ok(blob) = global_session.save_core_module(ArchiveType::RiffDeflate)
+                    && let Err(_err) = write_core_module_cache(&path, blob.as_slice())
+                {
+                    #[cfg(feature = "trace")]
+                    tracing::warn!("failed to write core module cache {path:?}: {_err}");
+                }
+                Ok(global_session)
+            }
+        }
+    }
+}
+
+/// Write `core_module` aside and rename it into place, so that concurrent builders never read
+/// a partially written cache.
+fn write_core_module_cache(path: &Path, core_module: &[u8]) -> std::io::Result<()> {
+    static TEMP_ID: AtomicUsize = AtomicUsize::new(0);
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let mut temp_path = path.to_path_buf().into_os_string();
+    temp_path.push(format!(
+        ".{}.{}.tmp",
+        std::process::id(),
+        TEMP_ID.fetch_add(1, Ordering::Relaxed)
+    ));
+
+    let result =
+        std::fs::write(&temp_path, core_module).and_then(|()| std::fs::rename(&temp_path, path));
+    if result.is_err() {
+        let _ = std::fs::remove_file(&temp_path);
+    }
+    result
+}
+
+/// See [`GlobalSession::elapsed_time`].
+///
+/// The counters are cumulative for the whole global session, not per call. The difference
+/// between two readings only covers one compile if nothing else compiles with the same global
+/// session in between. Otherwise it includes the time of the other compiles too, and so do the
+/// `total_time` and `downstream_time` fields of the `trace` spans.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CompileTimings {
+    /// Total time spent in Slang, including `downstream`.
+    pub total: Duration,
+    /// Time spent in downstream compilers such as DXC or glslang.
+    pub downstream: Duration,
+}
+
+/// Time taken between two calls to [`GlobalSession::elapsed_time`].
+impl core::ops::Sub for CompileTimings {
+    type Output = CompileTimings;
+
+    fn sub(self, earlier: CompileTimings) -> CompileTimings {
+        CompileTimings {
+            total: self.total.saturating_sub(earlier.total),
+            downstream: self.downstream.saturating_sub(earlier.downstream),
+        }
+    }
 }
 
 #[repr(transparent)]
@@ -327,8 +800,123 @@ unsafe impl Interface for Session {
     }
 }
 
+/// Lower `arguments` to the C representation. Expression strings are kept alive in
+/// `c_string_exprs`, which has to outlive the returned arguments.
+fn specialization_args(
+    arguments: &[SpecializeArgument],
+    c_string_exprs: &mut Vec<CString>,
+) -> Vec<sys::slang_SpecializationArg> {
+    arguments
+        .iter()
+        .map(|arg| match arg {
+            SpecializeArgument::Type(ty) => sys::slang_SpecializationArg {
+                kind: sys::slang_SpecializationArg_Kind::Type,
+                __bindgen_anon_1: sys::slang_SpecializationArg__bindgen_ty_1 {
+                    type_: unsafe { ty.as_raw() } as _,
+                },
+            },
+            SpecializeArgument::Expr(expr) => {
+                let c_string = CString::new(*expr).unwrap();
+                c_string_exprs.push(c_string);
+                let c_string = c_string_exprs.last().unwrap().as_ptr();
+                sys::slang_SpecializationArg {
+                    kind: sys::slang_SpecializationArg_Kind::Expr,
+                    __bindgen_anon_1: sys::slang_SpecializationArg__bindgen_ty_1 { expr: c_string },
+                }
+            }
+        })
+        .collect()
+}
+
+/// Interfaces whose diagnostics are delivered to their session's [`DiagnosticSink`].
+trait SessionScoped {
+    fn session_ptr(&self) -> *mut c_void;
+}
+
+impl SessionScoped for Session {
+    fn session_ptr(&self) -> *mut c_void {
+        unsafe { self.as_raw() }
+    }
+}
+
+impl SessionScoped for ComponentType {
+    fn session_ptr(&self) -> *mut c_void {
+        vcall!(self, getSession()) as _
+    }
+}
+
+impl SessionScoped for Module {
+    fn session_ptr(&self) -> *mut c_void {
+        self.0.session_ptr()
+    }
+}
+
+struct DiagnosticRegistration {
+    id: usize,
+    /// Keeps the session alive, so its address can't be reused by another session while this
+    /// registration exists.
+    session: Session,
+    sink: Arc<dyn DiagnosticSink>,
+}
+
+/// Number of registered sinks, so calls don't touch [`DIAGNOSTIC_SINKS`] when there are none.
+static DIAGNOSTIC_SINK_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// Sinks registered with [`Session::register_diagnostic_sink`]. Slang has no per-session user
+/// data, so sinks are looked up by session address. Reporting only takes a read lock.
+static DIAGNOSTIC_SINKS: RwLock<Vec<DiagnosticRegistration>> = RwLock::new(Vec::new());
+
+/// Forward `out_diagnostics` to the sink registered for `source`'s session, if any. This
+/// doesn't take ownership of `out_diagnostics`.
+fn report_diagnostics(source: &impl SessionScoped, out_diagnostics: *mut sys::ISlangBlob) {
+    if out_diagnostics.is_null() || DIAGNOSTIC_SINK_COUNT.load(Ordering::Acquire) == 0 {
+        return;
+    }
+
+    let sink = {
+        let session = source.session_ptr();
+        let sinks = DIAGNOSTIC_SINKS.read().unwrap();
+        sinks
+            .iter()
+            .rev()
+            .find(|registration| registration.session.session_ptr() == session)
+            .map(|registration| registration.sink.clone())
+    };
+
+    // Called without the lock held, so a sink may register or drop sinks itself.
+    if let Some(sink) = sink {
+        let diagnostics = Blob(Unknown::new_with_ref(out_diagnostics).unwrap());
+        if !diagnostics.as_slice().is_empty() {
+            sink.diagnostics(&String::from_utf8_lossy(diagnostics.as_slice()));
+        }
+    }
+}
+
+/// Keeps a [`DiagnosticSink`] registered with [`Session::register_diagnostic_sink`].
+#[must_use = "the sink is unregistered when this is dropped"]
+pub struct DiagnosticSinkRegistration {
+    id: usize,
+}
+
+impl Drop for DiagnosticSinkRegistration {
+    fn drop(&mut self) {
+        let registration = {
+            let mut sinks = DIAGNOSTIC_SINKS.write().unwrap();
+            let registration = sinks
+                .iter()
+                .position(|registration| registration.id == self.id)
+                .map(|index| sinks.remove(index));
+            DIAGNOSTIC_SINK_COUNT.store(sinks.len(), Ordering::Release);
+            registration
+        };
+        // The session and the sink are released outside the lock.
+        drop(registration);
+    }
+}
+
 macro_rules! into_module {
-    ($self:ident, $module:ident, $err_code:expr, $diagnostics:ident) => {
+    ($self:ident, $module:ident, $err_code:expr, $diagnostics:ident) => {{
+        report_diagnostics($self, $diagnostics);
         match Unknown::new_with_ref($module) {
             Some(u) => {
                 #[cfg(feature = "trace")]
@@ -349,10 +937,41 @@ macro_rules! into_module {
                 None => Err(Error::Code($err_code)),
             },
         }
-    };
+    }};
 }
 
 impl Session {
+    /// Deliver the diagnostics of every call made through this session, or through component
+    /// types created from it, to `sink`. Warnings are delivered even when the call succeeds.
+    /// Errors are delivered as well as being returned as [`Error::Blob`].
+    ///
+    /// The sink stays registered, and keeps the session alive, until the returned value is
+    /// dropped. When several sinks are registered for a session, the most recent one receives
+    /// the diagnostics.
+    pub fn register_diagnostic_sink(
+        &self,
+        sink: impl DiagnosticSink + 'static,
+    ) -> DiagnosticSinkRegistration {
+        static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+        let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+
+        let mut sinks = DIAGNOSTIC_SINKS.write().unwrap();
+        sinks.push(DiagnosticRegistration {
+            id,
+            session: self.clone(),
+            sink: Arc::new(sink),
+        });
+        DIAGNOSTIC_SINK_COUNT.store(sinks.len(), Ordering::Release);
+
+        DiagnosticSinkRegistration { id }
+    }
+
+    /// The global session this session was created from.
+    pub fn global_session(&self) -> GlobalSession {
+        let global_session = vcall!(self, getGlobalSession());
+        GlobalSession(Unknown::new_with_ref(global_session).unwrap())
+    }
+
     /** Load a module as it would be by code using `import`. */
     pub fn load_module(&self, module_name: &str) -> Result<Module> {
         let module_name = CString::new(module_name).map_err(|_| Error::Unknown)?;
@@ -506,8 +1125,256 @@ impl Session {
             Unknown::new_with_ref(composite_component_type).unwrap(),
         ))
     }
+
+    /// Specialize a generic type, e.g. `Buffer<T>` with `T = Particle`.
+    pub fn specialize_type(
+        &self,
+        ty: &reflect::Type,
+        arguments: &[SpecializeArgument],
+    ) -> Result<&reflect::Type> {
+        let mut c_string_exprs = Vec::new();
+        let args = specialization_args(arguments, &mut c_string_exprs);
+        let mut out_diagnostics = null_mut();
+        let ptr = vcall!(
+            self,
+            specializeType(
+                ty.as_raw() as _,
+                args.as_ptr(),
+                args.len() as _,
+                &mut out_diagnostics
+            )
+        );
+        report_diagnostics(self, out_diagnostics);
+        unsafe { reflect::ptr_or_diagnostics(ptr, out_diagnostics) }
+    }
+
+    /// Compute the layout of an arbitrary type for the target at index `target`, without it
+    /// having to appear in a shader.
+    pub fn type_layout(
+        &self,
+        ty: &reflect::Type,
+        target: i64,
+        rules: LayoutRules,
+    ) -> Result<&reflect::TypeLayout> {
+        let mut out_diagnostics = null_mut();
+        let ptr = vcall!(
+            self,
+            getTypeLayout(ty.as_raw() as _, target, rules, &mut out_diagnostics)
+        );
+        report_diagnostics(self, out_diagnostics);
+        unsafe { reflect::ptr_or_diagnostics(ptr, out_diagnostics) }
+    }
+
+    /// Wrap `ty` in a container, e.g. `StructuredBuffer<ty>` for [`ContainerType::StructuredBuffer`].
+    pub fn container_type(
+        &self,
+        ty: &reflect::Type,
+        container: ContainerType,
+    ) -> Result<&reflect::Type> {
+        let mut out_diagnostics = null_mut();
+        let ptr = vcall!(
+            self,
+            getContainerType(ty.as_raw() as _, container, &mut out_diagnostics)
+        );
+        report_diagnostics(self, out_diagnostics);
+        unsafe { reflect::ptr_or_diagnostics(ptr, out_diagnostics) }
+    }
+
+    /// The `__Dynamic` type used to request dynamic dispatch when specializing.
+    pub fn dynamic_type(&self) -> Option<&reflect::Type> {
+        let ptr = vcall!(self, getDynamicType());
+        (!ptr.is_null()).then(|| unsafe { &*(ptr as *const reflect::Type) })
+    }
+
+    /// Create a component that makes `ty`'s conformance to `interface` available for dynamic
+    /// dispatch. Include it in [`Session::create_composite_component_type`] alongside the
+    /// modules that use the interface.
+    ///
+    /// `id_override` fixes the conformance ID of `ty` instead of letting the compiler assign one.
+    pub fn create_type_conformance(
+        &self,
+        ty: &reflect::Type,
+        interface: &reflect::Type,
+        id_override: Option<i64>,
+    ) -> Result<TypeConformance> {
+        let mut type_conformance = null_mut();
+        vcall_maybe_diagnostics!(
+            self,
+            createTypeConformanceComponentType(
+                ty.as_raw() as _,
+                interface.as_raw() as _,
+                &mut type_conformance,
+                id_override.unwrap_or(-1)
+            )
+        )?;
+        Ok(TypeConformance(ComponentType(
+            Unknown::new_with_ref(type_conformance).unwrap(),
+        )))
+    }
+
+    /// Mangled name of the RTTI object for `ty`.
+    pub fn type_rtti_mangled_name(&self, ty: &reflect::Type) -> Result<String> {
+        let mut out_name = null_mut();
+        vcall_maybe!(
+            self,
+            getTypeRTTIMangledName(ty.as_raw() as _, &mut out_name)
+        )?;
+        let name = Blob(Unknown::new_with_ref(out_name).ok_or(Error::Unknown)?);
+        Ok(String::from_utf8_lossy(name.as_slice()).into_owned())
+    }
+
+    /// Mangled name of the witness table for `ty`'s conformance to `interface`.
+    pub fn type_conformance_witness_mangled_name(
+        &self,
+        ty: &reflect::Type,
+        interface: &reflect::Type,
+    ) -> Result<String> {
+        let mut out_name = null_mut();
+        vcall_maybe!(
+            self,
+            getTypeConformanceWitnessMangledName(
+                ty.as_raw() as _,
+                interface.as_raw() as _,
+                &mut out_name
+            )
+        )?;
+        let name = Blob(Unknown::new_with_ref(out_name).ok_or(Error::Unknown)?);
+        Ok(String::from_utf8_lossy(name.as_slice()).into_owned())
+    }
+
+    /// ID identifying `ty`'s conformance to `interface` in a dynamic object. This is the value
+    /// to write into the type ID slot of an interface-typed parameter.
+    pub fn type_conformance_witness_sequential_id(
+        &self,
+        ty: &reflect::Type,
+        interface: &reflect::Type,
+    ) -> Result<u32> {
+        let mut id = 0;
+        vcall_maybe!(
+            self,
+            getTypeConformanceWitnessSequentialID(
+                ty.as_raw() as _,
+                interface.as_raw() as _,
+                &mut id
+            )
+        )?;
+        Ok(id)
+    }
+
+    /// The 16 byte RTTI header that precedes the data of a dynamic object of type `ty` stored as
+    /// `interface`.
+    pub fn dynamic_object_rtti_bytes(
+        &self,
+        ty: &reflect::Type,
+        interface: &reflect::Type,
+    ) -> Result<[u32; 4]> {
+        let mut out = [0; 4];
+        vcall_maybe!(
+            self,
+            getDynamicObjectRTTIBytes(
+                ty.as_raw() as _,
+                interface.as_raw() as _,
+                out.as_mut_ptr(),
+                size_of_val(&out) as _
+            )
+        )?;
+        Ok(out)
+    }
+}
+
+#[repr(transparent)]
+#[derive(Clone)]
+pub struct SharedLibrary(Castable);
+
+impl Deref for SharedLibrary {
+    type Target = Castable;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+unsafe impl Interface for SharedLibrary {
+    type Vtable = sys::ISlangSharedLibrary_vtable;
+    const UUID: Uuid = uuid(
+        0x70dbc7c4,
+        0xdc3b,
+        0x4a07,
+        [0xae, 0x7e, 0x75, 0x2a, 0xf6, 0xa8, 0x15, 0x55],
+    );
+
+    fn new(unknown: Unknown) -> Self {
+        Self(Castable(unknown))
+    }
+}
+
+impl SharedLibrary {
+    /// Get the address of a symbol by name, or `None` if it isn't found.
+    pub fn find_symbol(&self, name: &str) -> Option<NonNull<c_void>> {
+        let name = CString::new(name).ok()?;
+        NonNull::new(vcall!(self, findSymbolAddressByName(name.as_ptr())))
+    }
+
+    /// Get a function by name as the function pointer type `F`, e.g. [`ComputeFunc`] for a
+    /// compute entry point.
+    ///
+    /// # Safety
+    /// `F` must be a function pointer type, e.g. `extern "C" fn(..)`, whose ABI, parameters and
+    /// return type match the symbol. Slang's kernels use the C calling convention. Only the size
+    /// of `F` is checked, at compile time, so any other pointer-sized type is also accepted.
+    pub unsafe fn find_func_by_name<F: Copy>(&self, name: &str) -> Option<F> {
+        const { assert!(size_of::<F>() == size_of::<*const c_void>()) };
+        let ptr = self.find_symbol(name)?;
+        Some(unsafe { core::mem::transmute_copy(&ptr) })
+    }
 }
 
+#[repr(transparent)]
+#[derive(Clone)]
+pub struct SharedLibraryLoader(Unknown);
+
+unsafe impl Interface for SharedLibraryLoader {
+    type Vtable = sys::ISlangSharedLibraryLoader_vtable;
+    const UUID: Uuid = uuid(
+        0x6264ab2b,
+        0xa3e8,
+        0x4a06,
+        [0x97, 0xf1, 0x49, 0xbc, 0x2d, 0x2a, 0xb1, 0x4d],
+    );
+
+    fn new(unknown: Unknown) -> Self {
+        Self(unknown)
+    }
+}
+
+impl SharedLibraryLoader {
+    pub fn load_shared_library(&self, path: &str) -> Result<SharedLibrary> {
+        let path = CString::new(path).map_err(|_| Error::Unknown)?;
+        let mut shared_library = null_mut();
+        vcall_maybe!(self, loadSharedLibrary(path.as_ptr(), &mut shared_library))?;
+        Unknown::new(shared_library)
+            .map(|unknown| SharedLibrary(Castable(unknown)))
+            .ok_or(Error::Unknown)
+    }
+}
+
+/// Range of thread groups to run in one call to a [`ComputeFunc`].
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ComputeVaryingInput {
+    pub start_group_id: [u32; 3],
+    pub end_group_id: [u32; 3],
+}
+
+/// Signature of a compute entry point compiled for [`CompileTarget::ShaderHostCallable`].
+/// `entry_point_params` and `global_params` point to the entry point's and the program's
+/// uniform parameters, laid out as reported by reflection.
+pub type ComputeFunc = unsafe extern "C" fn(
+    varying_input: *mut ComputeVaryingInput,
+    entry_point_params: *mut c_void,
+    global_params: *mut c_void,
+);
+
 #[repr(transparent)]
 #[derive(Clone)]
 pub struct Metadata(Castable);
@@ -609,35 +1476,19 @@ unsafe impl Interface for ComponentType {
 }
 
 impl ComponentType {
+    /// The session this component type was loaded or created in.
+    pub fn session(&self) -> Session {
+        let session = vcall!(self, getSession());
+        Session(Unknown::new_with_ref(session).unwrap())
+    }
+
     pub fn specialization_param_count(&self) -> usize {
         vcall!(self, getSpecializationParamCount()) as usize
     }
 
     pub fn specialize(&self, arguments: &[SpecializeArgument]) -> Result<ComponentType> {
         let mut c_string_exprs = Vec::new();
-
-        let args: Vec<_> = arguments
-            .iter()
-            .map(|arg| match arg {
-                SpecializeArgument::Type(ty) => sys::slang_SpecializationArg {
-                    kind: sys::slang_SpecializationArg_Kind::Type,
-                    __bindgen_anon_1: sys::slang_SpecializationArg__bindgen_ty_1 {
-                        type_: unsafe { ty.as_raw() } as _,
-                    },
-                },
-                SpecializeArgument::Expr(expr) => {
-                    let c_string = CString::new(*expr).unwrap();
-                    c_string_exprs.push(c_string);
-                    let c_string = c_string_exprs.last().unwrap().as_ptr();
-                    sys::slang_SpecializationArg {
-                        kind: sys::slang_SpecializationArg_Kind::Expr,
-                        __bindgen_anon_1: sys::slang_SpecializationArg__bindgen_ty_1 {
-                            expr: c_string,
-                        },
-                    }
-                }
-            })
-            .collect();
+        let args = specialization_args(arguments, &mut c_string_exprs);
 
         let arg_ptr = args.as_ptr();
         let arg_count = args.len() as i64;
@@ -650,6 +1501,7 @@ impl ComponentType {
     pub fn layout(&self, target: i64) -> Result<&reflect::Shader> {
         let mut out_diagnostics = null_mut();
         let ptr = vcall!(self, getLayout(target, &mut out_diagnostics));
+        report_diagnostics(self, out_diagnostics);
         if ptr.is_null() {
             if let Some(diagnostics) = Unknown::new_with_ref(out_diagnostics) {
                 Err(Error::Blob(Blob(diagnostics)))
@@ -679,25 +1531,100 @@ impl ComponentType {
     (and hence the global layout) that results will be deterministic,
     but is not currently documented. */
     pub fn link(&self) -> Result<ComponentType> {
+        #[cfg(feature = "trace")]
+        let (span, start) = compile_span!(self, "link");
+
+        let mut out_linked_component_type = null_mut();
+        let result = vcall_maybe_diagnostics!(self, link(&mut out_linked_component_type));
+
+        #[cfg(feature = "trace")]
+        self.record_timings(&span, start);
+
+        result?;
+        Ok(ComponentType(
+            Unknown::new_with_ref(out_linked_component_type).unwrap(),
+        ))
+    }
+
+    /// Create a copy of this component type whose entry point is exported as `new_name`. The
+    /// component type must contain exactly one entry point.
+    pub fn rename_entry_point(&self, new_name: &str) -> Result<ComponentType> {
+        let new_name = CString::new(new_name).map_err(|_| Error::Unknown)?;
+        let mut out_entry_point = null_mut();
+        vcall_maybe!(
+            self,
+            renameEntryPoint(new_name.as_ptr(), &mut out_entry_point)
+        )?;
+        Ok(ComponentType(
+            Unknown::new_with_ref(out_entry_point).unwrap(),
+        ))
+    }
+
+    /// Link with `options` applied on top of the session's options, for this program only.
+    pub fn link_with_options(&self, options: &CompilerOptions) -> Result<ComponentType> {
+        #[cfg(feature = "trace")]
+        let (span, start) = compile_span!(self, "link_with_options");
+
         let mut out_linked_component_type = null_mut();
-        vcall_maybe_diagnostics!(self, link(&mut out_linked_component_type))?;
+        let result = vcall_maybe_diagnostics!(
+            self,
+            linkWithOptions(
+                &mut out_linked_component_type,
+                options.options.len() as _,
+                options.options.as_ptr() as _
+            )
+        );
+
+        #[cfg(feature = "trace")]
+        self.record_timings(&span, start);
+
+        result?;
         Ok(ComponentType(
             Unknown::new_with_ref(out_linked_component_type).unwrap(),
         ))
     }
 
     pub fn target_code(&self, target: i64) -> Result<Blob> {
+        #[cfg(feature = "trace")]
+        let (span, start) = compile_span!(self, "target_code", target);
+
         let mut code = null_mut();
-        vcall_maybe_diagnostics!(self, getTargetCode(target, &mut code))?;
+        let result = vcall_maybe_diagnostics!(self, getTargetCode(target, &mut code));
+
+        #[cfg(feature = "trace")]
+        self.record_timings(&span, start);
+
+        result?;
         Ok(Blob(Unknown::new_with_ref(code).unwrap()))
     }
 
+    /// Record the time taken since `start` on a span made by `compile_span!`.
+    #[cfg(feature = "trace")]
+    fn record_timings(&self, span: &tracing::Span, start: CompileTimings) {
+        let timings = self.session().global_session().elapsed_time() - start;
+        span.record("total_time", timings.total.as_secs_f64());
+        span.record("downstream_time", timings.downstream.as_secs_f64());
+    }
+
     pub fn target_metadata(&self, target: i64) -> Result<Metadata> {
         let mut metadata = null_mut();
         vcall_maybe_diagnostics!(self, getTargetMetadata(target, &mut metadata))?;
         Ok(Metadata(Castable(Unknown::new_with_ref(metadata).unwrap())))
     }
 
+    /// Get the entry point at `index` compiled for a host callable target such as
+    /// [`CompileTarget::ShaderHostCallable`], loaded and ready to be called on the CPU.
+    pub fn entry_point_host_callable(&self, index: i64, target: i64) -> Result<SharedLibrary> {
+        let mut shared_library = null_mut();
+        vcall_maybe_diagnostics!(
+            self,
+            getEntryPointHostCallable(index as _, target as _, &mut shared_library)
+        )?;
+        Ok(SharedLibrary(Castable(
+            Unknown::new_with_ref(shared_library).unwrap(),
+        )))
+    }
+
     ///  Get the compiled code for the entry point at `entryPointIndex` for the chosen `targetIndex`
     ///
     ///   Entry point code can only be computed for a component type that
@@ -712,6 +1639,30 @@ impl ComponentType {
         vcall_maybe_diagnostics!(self, getEntryPointCode(index, target, &mut code))?;
         Ok(Blob(Unknown::new_with_ref(code).unwrap()))
     }
+
+    /// Metadata for the entry point at `index` alone, e.g. which parameters it actually uses.
+    pub fn entry_point_metadata(&self, index: i64, target: i64) -> Result<Metadata> {
+        let mut metadata = null_mut();
+        vcall_maybe_diagnostics!(self, getEntryPointMetadata(index, target, &mut metadata))?;
+        Ok(Metadata(Castable(Unknown::new_with_ref(metadata).unwrap())))
+    }
+
+    /// Hash of everything that contributes to the code of the entry point at `index`: its
+    /// source, dependencies, specialization and compiler options. Suitable as a cache key.
+    pub fn entry_point_hash(&self, index: i64, target: i64) -> Result<Blob> {
+        let mut hash = null_mut();
+        vcall!(self, getEntryPointHash(index, target, &mut hash));
+        Unknown::new_with_ref(hash).map(Blob).ok_or(Error::Unknown)
+    }
+
+    /// All artifacts emitted for the entry point at `index` as a virtual file system.
+    pub fn result_as_file_system(&self, index: i64, target: i64) -> Result<FileSystem> {
+        let mut file_system = null_mut();
+        vcall_maybe!(self, getResultAsFileSystem(index, target, &mut file_system))?;
+        Unknown::new_with_ref(file_system)
+            .map(FileSystem)
+            .ok_or(Error::Unknown)
+    }
 }
 
 #[repr(transparent)]
@@ -746,6 +1697,42 @@ impl EntryPoint {
     pub fn specialize(&self, arguments: &[SpecializeArgument]) -> Result<Self> {
         self.0.specialize(arguments).map(Self)
     }
+
+    /// See [`ComponentType::rename_entry_point`]. The result is no longer an `IEntryPoint`.
+    pub fn rename_entry_point(&self, new_name: &str) -> Result<ComponentType> {
+        self.0.rename_entry_point(new_name)
+    }
+
+    /// Reflection of the function this entry point was created from. Unlike
+    /// [`reflect::EntryPoint`] this is available before linking or picking a target.
+    pub fn function(&self) -> Option<&reflect::Function> {
+        let ptr = vcall!(self, getFunctionReflection());
+        (!ptr.is_null()).then(|| unsafe { &*(ptr as *const reflect::Function) })
+    }
+}
+
+#[repr(transparent)]
+#[derive(Clone)]
+pub struct TypeConformance(ComponentType);
+
+unsafe impl Interface for TypeConformance {
+    type Vtable = sys::ITypeConformance_vtable;
+    const UUID: Uuid = uuid(
+        0x73eb3147,
+        0xe544,
+        0x41b5,
+        [0xb8, 0xf0, 0xa2, 0x44, 0xdf, 0x21, 0x94, 0xb],
+    );
+
+    fn new(unknown: Unknown) -> Self {
+        Self(ComponentType(unknown))
+    }
+}
+
+impl From<TypeConformance> for ComponentType {
+    fn from(value: TypeConformance) -> Self {
+        value.0
+    }
 }
 
 #[repr(transparent)]
@@ -780,7 +1767,7 @@ impl Module {
     /// Find and an entry point by name.
     /// Note that this does not work in case the function is not explicitly designated as an entry
     /// point, e.g. using a `[shader("...")]` attribute. In such cases, consider using
-    /// `IModule::findAndCheckEntryPoint` instead.
+    /// [`Module::find_and_check_entry_point`] instead.
     pub fn find_entry_point_by_name(&self, name: &str) -> Result<EntryPoint> {
         let name = CString::new(name).map_err(|_| Error::Unknown)?;
         let mut out_entry_point = null_mut();
@@ -793,6 +1780,21 @@ impl Module {
         )))
     }
 
+    /// Find a function by name and check that it can be used as an entry point for `stage`.
+    /// Unlike [`Module::find_entry_point_by_name`] this works for functions without a
+    /// `[shader("...")]` attribute, as is common in HLSL.
+    pub fn find_and_check_entry_point(&self, name: &str, stage: Stage) -> Result<EntryPoint> {
+        let name = CString::new(name).map_err(|_| Error::Unknown)?;
+        let mut out_entry_point = null_mut();
+        vcall_maybe_diagnostics!(
+            self,
+            findAndCheckEntryPoint(name.as_ptr(), stage, &mut out_entry_point)
+        )?;
+        Ok(EntryPoint(ComponentType(
+            Unknown::new_with_ref(out_entry_point).unwrap(),
+        )))
+    }
+
     /// Get number of entry points defined in the module. An entry point defined in a module
     /// is by default not included in the linkage, so calls to `IComponentType::getEntryPointCount`
     /// on an `IModule` instance will always return 0. However `IModule::getDefinedEntryPointCount`
@@ -822,6 +1824,18 @@ impl Module {
         Ok(Blob(Unknown::new_with_ref(out).unwrap()))
     }
 
+    /// Get the module's IR as text.
+    pub fn disassemble(&self) -> Result<Blob> {
+        let mut out = null_mut();
+        vcall_maybe!(self, disassemble(&mut out))?;
+        Ok(Blob(Unknown::new_with_ref(out).unwrap()))
+    }
+
+    /// The session this module was loaded in.
+    pub fn session(&self) -> Session {
+        self.0.session()
+    }
+
     /// Write the serialized representation of this module to a file.
     pub fn write_to_file<P: AsRef<Path>>(&self, file_name: P) -> Result<()> {
         let file_name = CString::new(file_name.as_ref().to_str().ok_or(Error::Unknown)?)
@@ -873,4 +1887,11 @@ impl Module {
     pub fn dependency_file_paths(&self) -> impl ExactSizeIterator<Item = &str> {
         (0..self.dependency_file_count()).map(|i| self.get_dependency_file_path(i).unwrap())
     }
+
+    /// Get the root of the module's declaration tree. This covers every declaration in the
+    /// module, whether or not it is reachable from an entry point.
+    pub fn module_reflection(&self) -> Option<&reflect::Decl> {
+        let ptr = vcall!(self, getModuleReflection());
+        (!ptr.is_null()).then(|| unsafe { &*(ptr as *const reflect::Decl) })
+    }
 }
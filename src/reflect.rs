@@ -1,6 +1,50 @@
-use crate::{ParameterCategory, Stage};
+use crate::{
+    BindingType, Blob, DeclKind, Error, ImageFormat, ModifierId, ParameterCategory, Result, Stage,
+    Unknown,
+};
+use core::ptr::null_mut;
 use std::ffi::CString;
 
+mod snapshot;
+
+pub use snapshot::*;
+
+/// Count reported for unbounded arrays of resources.
+pub const UNBOUNDED_SIZE: usize = usize::MAX;
+
+/// Count or offset reported when it depends on unresolved generic parameters or link-time
+/// constants.
+pub const UNKNOWN_SIZE: usize = UNBOUNDED_SIZE - 1;
+
+/// Number of bindings in a [`BindingRange`] or descriptors in a [`DescriptorRange`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BindingCount {
+    Finite(usize),
+    /// An unbounded array of resources, reported by Slang as [`UNBOUNDED_SIZE`].
+    Unbounded,
+    /// Not known until specialization or linking, reported by Slang as [`UNKNOWN_SIZE`].
+    Unknown,
+}
+
+impl BindingCount {
+    fn from_raw(count: usize) -> Self {
+        match count {
+            UNBOUNDED_SIZE => Self::Unbounded,
+            UNKNOWN_SIZE => Self::Unknown,
+            count => Self::Finite(count),
+        }
+    }
+
+    /// The count, unless it is unbounded or unknown.
+    pub fn finite(self) -> Option<usize> {
+        match self {
+            Self::Finite(count) => Some(count),
+            Self::Unbounded | Self::Unknown => None,
+        }
+    }
+}
+
 macro_rules! rcall {
     ($f:ident($s:expr $(,$arg:expr)*)) => {
 		unsafe { sys::$f($s as *const _ as _ $(,$arg)*) }
@@ -19,6 +63,20 @@ macro_rules! rcall {
 	};
 }
 
+/// # Safety
+/// `ptr` must be null or point to a valid `T`, `out_diagnostics` must be null or a blob.
+pub(crate) unsafe fn ptr_or_diagnostics<'a, T, U>(
+    ptr: *mut U,
+    out_diagnostics: *mut sys::ISlangBlob,
+) -> Result<&'a T> {
+    let diagnostics = Unknown::new_with_ref(out_diagnostics);
+    if ptr.is_null() {
+        Err(diagnostics.map_or(Error::Unknown, |d| Error::Blob(Blob(d))))
+    } else {
+        Ok(unsafe { &*(ptr as *const T) })
+    }
+}
+
 #[repr(transparent)]
 pub struct Shader(sys::SlangProgramLayout);
 
@@ -27,6 +85,19 @@ impl Shader {
         rcall!(spReflection_getGlobalParamsVarLayout(self) as Option<&VariableLayout>)
     }
 
+    pub fn parameter_count(&self) -> u32 {
+        rcall!(spReflection_GetParameterCount(self))
+    }
+
+    pub fn parameter_by_index(&self, index: u32) -> Option<&VariableLayout> {
+        rcall!(spReflection_GetParameterByIndex(self, index) as Option<&VariableLayout>)
+    }
+
+    /// Global shader parameters, in declaration order.
+    pub fn parameters(&self) -> impl ExactSizeIterator<Item = &VariableLayout> {
+        (0..self.parameter_count()).map(|i| self.parameter_by_index(i).unwrap())
+    }
+
     pub fn entry_point_count(&self) -> usize {
         rcall!(spReflection_getEntryPointCount(self)) as _
     }
@@ -49,6 +120,11 @@ impl Shader {
         rcall!(spReflection_FindTypeByName(self, name.as_ptr()) as Option<&Type>)
     }
 
+    pub fn find_function_by_name(&self, name: &str) -> Option<&Function> {
+        let name = CString::new(name).unwrap();
+        rcall!(spReflection_FindFunctionByName(self, name.as_ptr()) as Option<&Function>)
+    }
+
     pub fn global_constant_buffer_binding(&self) -> u64 {
         rcall!(spReflection_getGlobalConstantBufferBinding(self))
     }
@@ -61,6 +137,23 @@ impl Shader {
     pub fn bindless_space_index(&self) -> u32 {
         rcall!(spReflection_getBindlessSpaceIndex(self)) as u32
     }
+
+    /// Serialize the whole program layout with the compiler's own JSON writer. This is the same
+    /// output `slangc -reflection-json` produces.
+    pub fn to_json(&self) -> Result<Blob> {
+        let mut out = null_mut();
+        let result = rcall!(spReflection_ToJson(self, null_mut(), &mut out));
+        if result < 0 {
+            return Err(Error::Code(result));
+        }
+        Unknown::new_with_ref(out).map(Blob).ok_or(Error::Unknown)
+    }
+
+    /// Same as [`Shader::to_json`], parsed into a [`serde_json::Value`].
+    #[cfg(feature = "serde")]
+    pub fn to_json_value(&self) -> Result<serde_json::Value> {
+        Ok(serde_json::from_slice(self.to_json()?.as_slice())?)
+    }
 }
 
 #[repr(transparent)]
@@ -71,10 +164,34 @@ impl EntryPoint {
         rcall!(spReflectionEntryPoint_getName(self) as Option<&str>)
     }
 
+    /// Name the entry point was renamed to, if any.
+    pub fn name_override(&self) -> Option<&str> {
+        rcall!(spReflectionEntryPoint_getNameOverride(self) as Option<&str>)
+    }
+
     pub fn stage(&self) -> Stage {
         rcall!(spReflectionEntryPoint_getStage(self))
     }
 
+    pub fn parameter_count(&self) -> u32 {
+        rcall!(spReflectionEntryPoint_getParameterCount(self))
+    }
+
+    pub fn parameter_by_index(&self, index: u32) -> Option<&VariableLayout> {
+        rcall!(spReflectionEntryPoint_getParameterByIndex(self, index) as Option<&VariableLayout>)
+    }
+
+    pub fn parameters(&self) -> impl ExactSizeIterator<Item = &VariableLayout> {
+        (0..self.parameter_count()).map(|i| self.parameter_by_index(i).unwrap())
+    }
+
+    /// Whether the entry point's `uniform` parameters were packed into an implicit default
+    /// constant buffer. How that buffer is bound depends on the target, e.g. it becomes a push
+    /// constant buffer on SPIR-V.
+    pub fn has_default_constant_buffer(&self) -> bool {
+        rcall!(spReflectionEntryPoint_hasDefaultConstantBuffer(self)) != 0
+    }
+
     pub fn compute_thread_group_size(&self) -> [u64; 3] {
         let mut out = [0; 3];
         rcall!(spReflectionEntryPoint_getComputeThreadGroupSize(
@@ -102,6 +219,10 @@ impl EntryPoint {
     pub fn result_var_layout(&self) -> Option<&VariableLayout> {
         rcall!(spReflectionEntryPoint_getResultVarLayout(self) as Option<&VariableLayout>)
     }
+
+    pub fn function(&self) -> Option<&Function> {
+        rcall!(spReflectionEntryPoint_getFunction(self) as Option<&Function>)
+    }
 }
 
 #[repr(transparent)]
@@ -111,6 +232,127 @@ impl Variable {
     pub fn name(&self) -> Option<&str> {
         rcall!(spReflectionVariable_GetName(self) as Option<&str>)
     }
+
+    pub fn ty(&self) -> Option<&Type> {
+        rcall!(spReflectionVariable_GetType(self) as Option<&Type>)
+    }
+
+    pub fn has_modifier(&self, modifier: ModifierId) -> bool {
+        !rcall!(spReflectionVariable_FindModifier(self, modifier)).is_null()
+    }
+
+    pub fn generic_container(&self) -> Option<&Generic> {
+        rcall!(spReflectionVariable_GetGenericContainer(self) as Option<&Generic>)
+    }
+
+    pub fn apply_specializations(&self, generic: &Generic) -> Option<&Variable> {
+        rcall!(
+            spReflectionVariable_applySpecializations(self, generic as *const _ as _)
+                as Option<&Variable>
+        )
+    }
+
+    pub fn user_attribute_count(&self) -> u32 {
+        rcall!(spReflectionVariable_GetUserAttributeCount(self))
+    }
+
+    pub fn user_attribute_by_index(&self, index: u32) -> Option<&UserAttribute> {
+        rcall!(spReflectionVariable_GetUserAttribute(self, index) as Option<&UserAttribute>)
+    }
+
+    pub fn user_attributes(&self) -> impl ExactSizeIterator<Item = &UserAttribute> {
+        (0..self.user_attribute_count()).map(|i| self.user_attribute_by_index(i).unwrap())
+    }
+
+    pub fn find_user_attribute_by_name(&self, name: &str) -> Option<&UserAttribute> {
+        self.user_attributes().find(|a| a.name() == Some(name))
+    }
+}
+
+#[repr(transparent)]
+pub struct Function(sys::SlangReflectionFunction);
+
+impl Function {
+    pub fn name(&self) -> Option<&str> {
+        rcall!(spReflectionFunction_GetName(self) as Option<&str>)
+    }
+
+    pub fn result_type(&self) -> Option<&Type> {
+        rcall!(spReflectionFunction_GetResultType(self) as Option<&Type>)
+    }
+
+    pub fn parameter_count(&self) -> u32 {
+        rcall!(spReflectionFunction_GetParameterCount(self))
+    }
+
+    pub fn parameter_by_index(&self, index: u32) -> Option<&Variable> {
+        rcall!(spReflectionFunction_GetParameter(self, index) as Option<&Variable>)
+    }
+
+    pub fn parameters(&self) -> impl ExactSizeIterator<Item = &Variable> {
+        (0..self.parameter_count()).map(|i| self.parameter_by_index(i).unwrap())
+    }
+
+    pub fn has_modifier(&self, modifier: ModifierId) -> bool {
+        !rcall!(spReflectionFunction_FindModifier(self, modifier)).is_null()
+    }
+
+    /// The generic this function is declared in, if any.
+    pub fn generic_container(&self) -> Option<&Generic> {
+        rcall!(spReflectionFunction_GetGenericContainer(self) as Option<&Generic>)
+    }
+
+    pub fn apply_specializations(&self, generic: &Generic) -> Option<&Function> {
+        rcall!(
+            spReflectionFunction_applySpecializations(self, generic as *const _ as _)
+                as Option<&Function>
+        )
+    }
+
+    /// Resolve a generic function by inferring its generic arguments from argument types.
+    pub fn specialize_with_arg_types(&self, arg_types: &[&Type]) -> Option<&Function> {
+        let mut arg_types: Vec<*const sys::SlangReflectionType> =
+            arg_types.iter().map(|ty| unsafe { ty.as_raw() }).collect();
+        rcall!(spReflectionFunction_specializeWithArgTypes(
+            self,
+            arg_types.len() as _,
+            arg_types.as_mut_ptr()
+        ) as Option<&Function>)
+    }
+
+    /// Whether this reflects an overload set rather than a single function, as returned by
+    /// [`Shader::find_function_by_name`] for overloaded names.
+    pub fn is_overloaded(&self) -> bool {
+        rcall!(spReflectionFunction_isOverloaded(self))
+    }
+
+    pub fn overload_count(&self) -> u32 {
+        rcall!(spReflectionFunction_getOverloadCount(self))
+    }
+
+    pub fn overload_by_index(&self, index: u32) -> Option<&Function> {
+        rcall!(spReflectionFunction_getOverload(self, index) as Option<&Function>)
+    }
+
+    pub fn overloads(&self) -> impl ExactSizeIterator<Item = &Function> {
+        (0..self.overload_count()).map(|i| self.overload_by_index(i).unwrap())
+    }
+
+    pub fn user_attribute_count(&self) -> u32 {
+        rcall!(spReflectionFunction_GetUserAttributeCount(self))
+    }
+
+    pub fn user_attribute_by_index(&self, index: u32) -> Option<&UserAttribute> {
+        rcall!(spReflectionFunction_GetUserAttribute(self, index) as Option<&UserAttribute>)
+    }
+
+    pub fn user_attributes(&self) -> impl ExactSizeIterator<Item = &UserAttribute> {
+        (0..self.user_attribute_count()).map(|i| self.user_attribute_by_index(i).unwrap())
+    }
+
+    pub fn find_user_attribute_by_name(&self, name: &str) -> Option<&UserAttribute> {
+        self.user_attributes().find(|a| a.name() == Some(name))
+    }
 }
 
 #[repr(transparent)]
@@ -165,6 +407,163 @@ impl VariableLayout {
     pub fn semantic_index(&self) -> usize {
         rcall!(spReflectionVariableLayout_GetSemanticIndex(self))
     }
+
+    /// Stage this variable belongs to. Only set for entry point parameters.
+    pub fn stage(&self) -> Stage {
+        rcall!(spReflectionVariableLayout_getStage(self))
+    }
+}
+
+#[repr(transparent)]
+pub struct Decl(sys::SlangReflectionDecl);
+
+impl Decl {
+    pub fn name(&self) -> Option<&str> {
+        rcall!(spReflectionDecl_getName(self) as Option<&str>)
+    }
+
+    pub fn kind(&self) -> DeclKind {
+        rcall!(spReflectionDecl_getKind(self))
+    }
+
+    pub fn child_count(&self) -> u32 {
+        rcall!(spReflectionDecl_getChildrenCount(self))
+    }
+
+    pub fn child_by_index(&self, index: u32) -> Option<&Decl> {
+        rcall!(spReflectionDecl_getChild(self, index) as Option<&Decl>)
+    }
+
+    pub fn children(&self) -> impl ExactSizeIterator<Item = &Decl> {
+        (0..self.child_count()).map(|i| self.child_by_index(i).unwrap())
+    }
+
+    /// Enclosing declaration, `None` for the module itself.
+    pub fn parent(&self) -> Option<&Decl> {
+        rcall!(spReflectionDecl_getParent(self) as Option<&Decl>)
+    }
+
+    /// Type declared by a struct or enum declaration.
+    pub fn ty(&self) -> Option<&Type> {
+        rcall!(spReflection_getTypeFromDecl(self) as Option<&Type>)
+    }
+
+    pub fn has_modifier(&self, modifier: ModifierId) -> bool {
+        !rcall!(spReflectionDecl_findModifier(self, modifier)).is_null()
+    }
+
+    pub fn as_function(&self) -> Option<&Function> {
+        rcall!(spReflectionDecl_castToFunction(self) as Option<&Function>)
+    }
+
+    pub fn as_variable(&self) -> Option<&Variable> {
+        rcall!(spReflectionDecl_castToVariable(self) as Option<&Variable>)
+    }
+
+    pub fn as_generic(&self) -> Option<&Generic> {
+        rcall!(spReflectionDecl_castToGeneric(self) as Option<&Generic>)
+    }
+}
+
+#[repr(transparent)]
+pub struct UserAttribute(sys::SlangReflectionUserAttribute);
+
+impl UserAttribute {
+    pub fn name(&self) -> Option<&str> {
+        rcall!(spReflectionUserAttribute_GetName(self) as Option<&str>)
+    }
+
+    pub fn argument_count(&self) -> u32 {
+        rcall!(spReflectionUserAttribute_GetArgumentCount(self))
+    }
+
+    pub fn argument_type(&self, index: u32) -> Option<&Type> {
+        rcall!(spReflectionUserAttribute_GetArgumentType(self, index) as Option<&Type>)
+    }
+
+    pub fn argument_value_int(&self, index: u32) -> Option<i32> {
+        let mut out = 0;
+        let result = rcall!(spReflectionUserAttribute_GetArgumentValueInt(
+            self, index, &mut out
+        ));
+        (result >= 0).then_some(out)
+    }
+
+    pub fn argument_value_float(&self, index: u32) -> Option<f32> {
+        let mut out = 0.0;
+        let result = rcall!(spReflectionUserAttribute_GetArgumentValueFloat(
+            self, index, &mut out
+        ));
+        (result >= 0).then_some(out)
+    }
+
+    /// String literal argument at `index`, without the surrounding quotes.
+    pub fn argument_value_string(&self, index: u32) -> Option<&str> {
+        let mut size = 0;
+        let ptr = rcall!(spReflectionUserAttribute_GetArgumentValueString(
+            self, index, &mut size
+        ));
+        if ptr.is_null() {
+            return None;
+        }
+        let bytes = unsafe { core::slice::from_raw_parts(ptr as *const u8, size) };
+        let value = core::str::from_utf8(bytes).ok()?;
+        // The compiler hands back the literal's token, quotes included.
+        Some(
+            value
+                .strip_prefix('"')
+                .and_then(|v| v.strip_suffix('"'))
+                .unwrap_or(value),
+        )
+    }
+
+    /// Argument at `index`, typed by the kind of literal it was written as. Returns `None` if
+    /// `index` is out of range.
+    pub fn argument(&self, index: u32) -> Option<UserAttributeArgument<'_>> {
+        if index >= self.argument_count() {
+            return None;
+        }
+
+        let value = if let Some(value) = self.argument_value_int(index) {
+            UserAttributeArgument::Int(value)
+        } else if let Some(value) = self.argument_value_float(index) {
+            UserAttributeArgument::Float(value)
+        } else if let Some(value) = self.argument_value_string(index) {
+            UserAttributeArgument::String(value)
+        } else {
+            UserAttributeArgument::Other(self.argument_type(index))
+        };
+        Some(value)
+    }
+
+    pub fn arguments(&self) -> impl ExactSizeIterator<Item = UserAttributeArgument<'_>> {
+        (0..self.argument_count()).map(|i| self.argument(i).unwrap())
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum UserAttributeArgument<'a> {
+    Int(i32),
+    Float(f32),
+    String(&'a str),
+    /// An argument that isn't an integer, float or string literal, with its type if known.
+    Other(Option<&'a Type>),
+}
+
+impl PartialEq for UserAttributeArgument<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        use UserAttributeArgument as A;
+        match (self, other) {
+            (A::Int(a), A::Int(b)) => a == b,
+            (A::Float(a), A::Float(b)) => a == b,
+            (A::String(a), A::String(b)) => a == b,
+            (A::Other(a), A::Other(b)) => match (a, b) {
+                (Some(a), Some(b)) => core::ptr::eq(*a, *b),
+                (a, b) => a.is_none() && b.is_none(),
+            },
+            _ => false,
+        }
+    }
 }
 
 #[repr(transparent)]
@@ -174,12 +573,112 @@ pub use ty::*;
 mod ty {
     use super::*;
     use crate::{ResourceAccess, ResourceShape};
+    use core::fmt::Debug;
+
+    impl Debug for Type {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.debug_struct("Type").field("name", &self.name()).finish()
+        }
+    }
 
     impl Type {
         pub(crate) unsafe fn as_raw(&self) -> *const sys::SlangReflectionType {
             self as *const _ as _
         }
 
+        /// Unqualified name of the type, e.g. `Foo` for `MyModule.Foo<int>`.
+        pub fn name(&self) -> Option<&str> {
+            rcall!(spReflectionType_GetName(self) as Option<&str>)
+        }
+
+        /// Fully qualified name of the type, including generic arguments.
+        pub fn full_name(&self) -> Result<String> {
+            let mut out = null_mut();
+            let result = rcall!(spReflectionType_GetFullName(self, &mut out));
+            if result < 0 {
+                return Err(Error::Code(result));
+            }
+            let blob = Unknown::new_with_ref(out).map(Blob).ok_or(Error::Unknown)?;
+            Ok(String::from_utf8_lossy(blob.as_slice()).into_owned())
+        }
+
+        pub fn kind(&self) -> crate::TypeKind {
+            rcall!(spReflectionType_GetKind(self))
+        }
+
+        pub fn as_scalar(&self) -> Option<ScalarType<'_>> {
+            (self.kind() == crate::TypeKind::Scalar).then_some(ScalarType(self))
+        }
+
+        pub fn as_struct(&self) -> Option<StructType<'_>> {
+            (self.kind() == crate::TypeKind::Struct).then_some(StructType(self))
+        }
+
+        /// Arrays and vectors.
+        pub fn as_array(&self) -> Option<ArrayType<'_>> {
+            matches!(
+                self.kind(),
+                crate::TypeKind::Array | crate::TypeKind::Vector
+            )
+            .then_some(ArrayType(self))
+        }
+
+        pub fn as_resource(&self) -> Option<ResourceType<'_>> {
+            (self.kind() == crate::TypeKind::Resource).then_some(ResourceType(self))
+        }
+
+        pub fn field_count(&self) -> u32 {
+            rcall!(spReflectionType_GetFieldCount(self))
+        }
+
+        pub fn field_by_index(&self, index: u32) -> Option<&Variable> {
+            rcall!(spReflectionType_GetFieldByIndex(self, index) as Option<&Variable>)
+        }
+
+        pub fn fields(&self) -> impl ExactSizeIterator<Item = &Variable> {
+            (0..self.field_count()).map(|i| self.field_by_index(i).unwrap())
+        }
+
+        /// Element count of an array type once the program's specialization is applied. Unlike
+        /// the plain element count, this resolves arrays sized by link-time constants.
+        pub fn specialized_element_count(&self, shader: &Shader) -> usize {
+            rcall!(spReflectionType_GetSpecializedElementCount(
+                self,
+                shader as *const _ as _
+            ))
+        }
+
+        pub fn user_attribute_count(&self) -> u32 {
+            rcall!(spReflectionType_GetUserAttributeCount(self))
+        }
+
+        pub fn user_attribute_by_index(&self, index: u32) -> Option<&UserAttribute> {
+            rcall!(spReflectionType_GetUserAttribute(self, index) as Option<&UserAttribute>)
+        }
+
+        pub fn user_attributes(&self) -> impl ExactSizeIterator<Item = &UserAttribute> {
+            (0..self.user_attribute_count()).map(|i| self.user_attribute_by_index(i).unwrap())
+        }
+
+        pub fn find_user_attribute_by_name(&self, name: &str) -> Option<&UserAttribute> {
+            let name = CString::new(name).unwrap();
+            rcall!(
+                spReflectionType_FindUserAttributeByName(self, name.as_ptr())
+                    as Option<&UserAttribute>
+            )
+        }
+
+        pub fn generic_container(&self) -> Option<&Generic> {
+            rcall!(spReflectionType_GetGenericContainer(self) as Option<&Generic>)
+        }
+
+        pub fn apply_specializations(&self, generic: &Generic) -> Option<&Type> {
+            rcall!(
+                spReflectionType_applySpecializations(self, generic as *const _ as _)
+                    as Option<&Type>
+            )
+        }
+
         pub(super) unsafe fn scalar_type(&self) -> crate::ScalarType {
             rcall!(spReflectionType_GetScalarType(self))
         }
@@ -223,10 +722,33 @@ mod ty {
     }
 
     #[derive(Clone, Copy)]
-    #[allow(unused)]
     pub struct StructType<'a>(pub(super) &'a Type);
 
-    impl<'a> StructType<'a> {}
+    impl<'a> StructType<'a> {
+        pub fn name(self) -> Option<&'a str> {
+            self.0.name()
+        }
+
+        pub fn field_count(self) -> u32 {
+            self.0.field_count()
+        }
+
+        pub fn field_by_index(self, index: u32) -> Option<&'a Variable> {
+            self.0.field_by_index(index)
+        }
+
+        pub fn fields(self) -> impl ExactSizeIterator<Item = &'a Variable> {
+            self.0.fields()
+        }
+
+        pub fn user_attributes(self) -> impl ExactSizeIterator<Item = &'a UserAttribute> {
+            self.0.user_attributes()
+        }
+
+        pub fn find_user_attribute_by_name(self, name: &str) -> Option<&'a UserAttribute> {
+            self.0.find_user_attribute_by_name(name)
+        }
+    }
 
     #[derive(Clone, Copy)]
     pub struct ArrayType<'a>(pub(super) &'a Type);
@@ -236,6 +758,10 @@ mod ty {
             unsafe { self.0.element_count() }
         }
 
+        pub fn specialized_element_count(self, shader: &Shader) -> usize {
+            self.0.specialized_element_count(shader)
+        }
+
         pub fn element_type(self) -> Option<&'a Type> {
             unsafe { self.0.element_type() }
         }
@@ -267,6 +793,362 @@ pub enum SingleElementContainerType {
     ShaderStorageBuffer,
 }
 
+#[repr(transparent)]
+pub struct Generic(sys::SlangReflectionGeneric);
+
+#[repr(transparent)]
+pub struct TypeParameter(sys::SlangReflectionTypeParameter);
+
+pub use generic::*;
+mod generic {
+    use super::*;
+
+    impl Generic {
+        pub fn name(&self) -> Option<&str> {
+            rcall!(spReflectionGeneric_GetName(self) as Option<&str>)
+        }
+
+        pub fn as_decl(&self) -> Option<&Decl> {
+            rcall!(spReflectionGeneric_asDecl(self) as Option<&Decl>)
+        }
+
+        /// Kind of the declaration this generic wraps, e.g. [`DeclKind::Func`].
+        pub fn inner_kind(&self) -> DeclKind {
+            rcall!(spReflectionGeneric_GetInnerKind(self))
+        }
+
+        pub fn inner_decl(&self) -> Option<&Decl> {
+            rcall!(spReflectionGeneric_GetInnerDecl(self) as Option<&Decl>)
+        }
+
+        pub fn outer_generic_container(&self) -> Option<&Generic> {
+            rcall!(spReflectionGeneric_GetOuterGenericContainer(self) as Option<&Generic>)
+        }
+
+        pub fn type_parameter_count(&self) -> u32 {
+            rcall!(spReflectionGeneric_GetTypeParameterCount(self))
+        }
+
+        pub fn type_parameter_by_index(&self, index: u32) -> Option<&Variable> {
+            rcall!(spReflectionGeneric_GetTypeParameter(self, index) as Option<&Variable>)
+        }
+
+        pub fn type_parameters(&self) -> impl ExactSizeIterator<Item = &Variable> {
+            (0..self.type_parameter_count()).map(|i| self.type_parameter_by_index(i).unwrap())
+        }
+
+        pub fn value_parameter_count(&self) -> u32 {
+            rcall!(spReflectionGeneric_GetValueParameterCount(self))
+        }
+
+        pub fn value_parameter_by_index(&self, index: u32) -> Option<&Variable> {
+            rcall!(spReflectionGeneric_GetValueParameter(self, index) as Option<&Variable>)
+        }
+
+        pub fn value_parameters(&self) -> impl ExactSizeIterator<Item = &Variable> {
+            (0..self.value_parameter_count()).map(|i| self.value_parameter_by_index(i).unwrap())
+        }
+
+        pub fn type_parameter_constraint_count(&self, type_param: &Variable) -> u32 {
+            rcall!(spReflectionGeneric_GetTypeParameterConstraintCount(
+                self,
+                type_param as *const _ as _
+            ))
+        }
+
+        pub fn type_parameter_constraint_by_index(
+            &self,
+            type_param: &Variable,
+            index: u32,
+        ) -> Option<&Type> {
+            rcall!(spReflectionGeneric_GetTypeParameterConstraintType(
+                self,
+                type_param as *const _ as _,
+                index
+            ) as Option<&Type>)
+        }
+
+        /// Interfaces `type_param` is required to conform to.
+        pub fn type_parameter_constraints<'a>(
+            &'a self,
+            type_param: &'a Variable,
+        ) -> impl ExactSizeIterator<Item = &'a Type> {
+            (0..self.type_parameter_constraint_count(type_param)).map(move |i| {
+                self.type_parameter_constraint_by_index(type_param, i)
+                    .unwrap()
+            })
+        }
+
+        /// Type bound to `type_param`, once this generic has been specialized.
+        pub fn concrete_type(&self, type_param: &Variable) -> Option<&Type> {
+            rcall!(
+                spReflectionGeneric_GetConcreteType(self, type_param as *const _ as _)
+                    as Option<&Type>
+            )
+        }
+
+        /// Value bound to `value_param`, once this generic has been specialized.
+        pub fn concrete_int_val(&self, value_param: &Variable) -> i64 {
+            rcall!(spReflectionGeneric_GetConcreteIntVal(
+                self,
+                value_param as *const _ as _
+            ))
+        }
+
+        pub fn apply_specializations(&self, generic: &Generic) -> Option<&Generic> {
+            rcall!(
+                spReflectionGeneric_applySpecializations(self, generic as *const _ as _)
+                    as Option<&Generic>
+            )
+        }
+    }
+
+    impl TypeParameter {
+        pub fn name(&self) -> Option<&str> {
+            rcall!(spReflectionTypeParameter_GetName(self) as Option<&str>)
+        }
+
+        pub fn index(&self) -> u32 {
+            rcall!(spReflectionTypeParameter_GetIndex(self))
+        }
+
+        pub fn constraint_count(&self) -> u32 {
+            rcall!(spReflectionTypeParameter_GetConstraintCount(self))
+        }
+
+        pub fn constraint_by_index(&self, index: u32) -> Option<&Type> {
+            rcall!(spReflectionTypeParameter_GetConstraintByIndex(self, index) as Option<&Type>)
+        }
+
+        pub fn constraints(&self) -> impl ExactSizeIterator<Item = &Type> {
+            (0..self.constraint_count()).map(|i| self.constraint_by_index(i).unwrap())
+        }
+    }
+
+    /// Argument for [`Shader::specialize_generic`].
+    #[derive(Clone, Copy, Debug)]
+    pub enum GenericArg<'a> {
+        Type(&'a Type),
+        Int(i64),
+        Bool(bool),
+    }
+
+    /// A parameter that has to be given an argument before a program can be linked, in the
+    /// order [`crate::ComponentType::specialize`] expects them.
+    #[derive(Clone, Copy)]
+    pub enum SpecializationParameter<'a> {
+        /// A global `type_param`.
+        Type(&'a TypeParameter),
+        /// A generic type parameter of an entry point.
+        EntryPointType(&'a Generic, &'a Variable),
+        /// A generic value parameter of an entry point, e.g. `<bool B>`.
+        EntryPointValue(&'a Generic, &'a Variable),
+        /// An interface typed shader parameter, global or of an entry point, that needs a
+        /// concrete type. The variable is the parameter, or the field of it, holding the
+        /// interface.
+        Existential(&'a Variable, &'a Type),
+    }
+
+    impl core::fmt::Debug for SpecializationParameter<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let kind = match self {
+                Self::Type(_) => "Type",
+                Self::EntryPointType(..) => "EntryPointType",
+                Self::EntryPointValue(..) => "EntryPointValue",
+                Self::Existential(..) => "Existential",
+            };
+            f.debug_tuple(kind).field(&self.name()).finish()
+        }
+    }
+
+    impl<'a> SpecializationParameter<'a> {
+        pub fn name(self) -> Option<&'a str> {
+            match self {
+                Self::Type(param) => param.name(),
+                Self::EntryPointType(_, param)
+                | Self::EntryPointValue(_, param)
+                | Self::Existential(param, _) => param.name(),
+            }
+        }
+
+        /// Interfaces a type argument has to conform to. Empty for value parameters.
+        pub fn constraints(self) -> Vec<&'a Type> {
+            match self {
+                Self::Type(param) => param.constraints().collect(),
+                Self::EntryPointType(generic, param) => {
+                    generic.type_parameter_constraints(param).collect()
+                }
+                Self::EntryPointValue(..) => Vec::new(),
+                Self::Existential(_, interface) => vec![interface],
+            }
+        }
+
+        /// Type of a value parameter. `None` for type parameters.
+        pub fn value_type(self) -> Option<&'a Type> {
+            match self {
+                Self::EntryPointValue(_, param) => param.ty(),
+                _ => None,
+            }
+        }
+    }
+
+    /// Push an [`SpecializationParameter::Existential`] for every interface reachable from
+    /// `param` without indirection, the way Slang walks a shader parameter's type.
+    fn collect_existentials<'a>(
+        param: &'a VariableLayout,
+        params: &mut Vec<SpecializationParameter<'a>>,
+    ) {
+        fn visit<'a>(
+            variable: &'a Variable,
+            ty: &'a Type,
+            params: &mut Vec<SpecializationParameter<'a>>,
+        ) {
+            match ty.kind() {
+                crate::TypeKind::Interface => {
+                    params.push(SpecializationParameter::Existential(variable, ty))
+                }
+                crate::TypeKind::Struct => {
+                    for field in ty.fields() {
+                        if let Some(field_ty) = field.ty() {
+                            visit(field, field_ty, params);
+                        }
+                    }
+                }
+                crate::TypeKind::Array
+                | crate::TypeKind::ConstantBuffer
+                | crate::TypeKind::ParameterBlock => {
+                    if let Some(element) = unsafe { ty.element_type() } {
+                        visit(variable, element, params);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(variable) = param.variable()
+            && let Some(ty) = variable.ty()
+        {
+            visit(variable, ty, params);
+        }
+    }
+
+    impl Shader {
+        pub fn type_parameter_count(&self) -> u32 {
+            rcall!(spReflection_GetTypeParameterCount(self))
+        }
+
+        pub fn type_parameter_by_index(&self, index: u32) -> Option<&TypeParameter> {
+            rcall!(spReflection_GetTypeParameterByIndex(self, index) as Option<&TypeParameter>)
+        }
+
+        pub fn type_parameters(&self) -> impl ExactSizeIterator<Item = &TypeParameter> {
+            (0..self.type_parameter_count()).map(|i| self.type_parameter_by_index(i).unwrap())
+        }
+
+        pub fn find_type_parameter(&self, name: &str) -> Option<&TypeParameter> {
+            let name = CString::new(name).unwrap();
+            rcall!(spReflection_FindTypeParameter(self, name.as_ptr()) as Option<&TypeParameter>)
+        }
+
+        /// Everything the program still needs to be specialized on, in the order Slang
+        /// numbers them: global type parameters and interface typed global parameters, then
+        /// for each entry point its generic parameters and interface typed parameters.
+        ///
+        /// For a composite of several modules, Slang numbers the global parameters module by
+        /// module instead. This order only matches [`crate::ComponentType::specialize`] when
+        /// the global parameters come from a single module.
+        pub fn specialization_parameters(
+            &self,
+        ) -> impl ExactSizeIterator<Item = SpecializationParameter<'_>> {
+            let mut params: Vec<_> = self
+                .type_parameters()
+                .map(SpecializationParameter::Type)
+                .collect();
+            for param in self.parameters() {
+                collect_existentials(param, &mut params);
+            }
+
+            for entry_point in self.entry_points() {
+                if let Some(generic) = entry_point
+                    .function()
+                    .and_then(|function| function.generic_container())
+                {
+                    // Type and value parameters are reported separately, but arguments are
+                    // matched by their position among the generic's members.
+                    let is_same = |decl: &Decl, param: &Variable| {
+                        core::ptr::eq(decl as *const Decl as *const (), param as *const _ as _)
+                    };
+                    for child in generic.as_decl().into_iter().flat_map(Decl::children) {
+                        if let Some(param) = generic.type_parameters().find(|p| is_same(child, p)) {
+                            params.push(SpecializationParameter::EntryPointType(generic, param));
+                        } else if let Some(param) =
+                            generic.value_parameters().find(|p| is_same(child, p))
+                        {
+                            params.push(SpecializationParameter::EntryPointValue(generic, param));
+                        }
+                    }
+                }
+                for param in entry_point.parameters() {
+                    collect_existentials(param, &mut params);
+                }
+            }
+
+            params.into_iter()
+        }
+
+        pub fn specialize_type(&self, ty: &Type, args: &[&Type]) -> Result<&Type> {
+            let mut args: Vec<*const sys::SlangReflectionType> =
+                args.iter().map(|ty| unsafe { ty.as_raw() }).collect();
+            let mut out_diagnostics = null_mut();
+            let ptr = rcall!(spReflection_specializeType(
+                self,
+                ty as *const _ as _,
+                args.len() as _,
+                args.as_mut_ptr(),
+                &mut out_diagnostics
+            ));
+            unsafe { ptr_or_diagnostics(ptr, out_diagnostics) }
+        }
+
+        pub fn specialize_generic(
+            &self,
+            generic: &Generic,
+            args: &[GenericArg],
+        ) -> Result<&Generic> {
+            let (arg_types, arg_vals): (Vec<_>, Vec<_>) = args
+                .iter()
+                .map(|arg| match *arg {
+                    GenericArg::Type(ty) => (
+                        sys::SlangReflectionGenericArgType::Type,
+                        sys::SlangReflectionGenericArg {
+                            typeVal: unsafe { ty.as_raw() } as _,
+                        },
+                    ),
+                    GenericArg::Int(value) => (
+                        sys::SlangReflectionGenericArgType::Int,
+                        sys::SlangReflectionGenericArg { intVal: value },
+                    ),
+                    GenericArg::Bool(value) => (
+                        sys::SlangReflectionGenericArgType::Bool,
+                        sys::SlangReflectionGenericArg { boolVal: value },
+                    ),
+                })
+                .unzip();
+
+            let mut out_diagnostics = null_mut();
+            let ptr = rcall!(spReflection_specializeGeneric(
+                self,
+                generic as *const _ as _,
+                args.len() as _,
+                arg_types.as_ptr(),
+                arg_vals.as_ptr(),
+                &mut out_diagnostics
+            ));
+            unsafe { ptr_or_diagnostics(ptr, out_diagnostics) }
+        }
+    }
+}
+
 #[repr(transparent)]
 pub struct TypeLayout(sys::SlangReflectionTypeLayout);
 
@@ -361,6 +1243,283 @@ mod type_layout {
         unsafe fn container_var_layout(&self) -> Option<&VariableLayout> {
             rcall!(spReflectionTypeLayout_getContainerVarLayout(self) as Option<&VariableLayout>)
         }
+
+        /// Number of binding ranges in this type layout, flattened over all of its fields.
+        pub fn binding_range_count(&self) -> usize {
+            rcall!(spReflectionTypeLayout_getBindingRangeCount(self)) as _
+        }
+
+        pub fn binding_range_by_index(&self, index: usize) -> Option<BindingRange<'_>> {
+            (index < self.binding_range_count()).then_some(BindingRange(self, index as _))
+        }
+
+        pub fn binding_ranges(&self) -> impl ExactSizeIterator<Item = BindingRange<'_>> {
+            (0..self.binding_range_count()).map(|i| BindingRange(self, i as _))
+        }
+
+        /// Index of the first binding range belonging to the field at `field_index`.
+        pub fn field_binding_range_offset(&self, field_index: usize) -> usize {
+            rcall!(spReflectionTypeLayout_getFieldBindingRangeOffset(
+                self,
+                field_index as _
+            )) as _
+        }
+
+        /// Index of the binding range used by the explicit counter of a structured buffer,
+        /// if there is one.
+        pub fn explicit_counter_binding_range_offset(&self) -> Option<usize> {
+            let offset = rcall!(spReflectionTypeLayout_getExplicitCounterBindingRangeOffset(
+                self
+            ));
+            (offset >= 0).then_some(offset as _)
+        }
+
+        /// Number of descriptor sets (register spaces) this type layout needs.
+        pub fn descriptor_set_count(&self) -> usize {
+            rcall!(spReflectionTypeLayout_getDescriptorSetCount(self)) as _
+        }
+
+        pub fn descriptor_set_by_index(&self, index: usize) -> Option<DescriptorSet<'_>> {
+            (index < self.descriptor_set_count()).then_some(DescriptorSet(self, index as _))
+        }
+
+        pub fn descriptor_sets(&self) -> impl ExactSizeIterator<Item = DescriptorSet<'_>> {
+            (0..self.descriptor_set_count()).map(|i| DescriptorSet(self, i as _))
+        }
+
+        /// Number of sub-object ranges, i.e. nested constant buffers, parameter blocks and
+        /// existential values that need their own binding.
+        pub fn sub_object_range_count(&self) -> usize {
+            rcall!(spReflectionTypeLayout_getSubObjectRangeCount(self)) as _
+        }
+
+        pub fn sub_object_range_by_index(&self, index: usize) -> Option<SubObjectRange<'_>> {
+            (index < self.sub_object_range_count()).then_some(SubObjectRange(self, index as _))
+        }
+
+        pub fn sub_object_ranges(&self) -> impl ExactSizeIterator<Item = SubObjectRange<'_>> {
+            (0..self.sub_object_range_count()).map(|i| SubObjectRange(self, i as _))
+        }
+    }
+
+    /// A contiguous range of bindings of the same [`BindingType`] inside a [`TypeLayout`].
+    #[derive(Clone, Copy)]
+    pub struct BindingRange<'a>(&'a TypeLayout, i64);
+
+    impl Debug for BindingRange<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.debug_struct("BindingRange")
+                .field("index", &self.1)
+                .field("binding_type", &self.binding_type())
+                .field("binding_count", &self.binding_count())
+                .field("descriptor_set_index", &self.descriptor_set_index())
+                .field(
+                    "leaf_variable",
+                    &self.leaf_variable().and_then(|v| v.name()),
+                )
+                .field(
+                    "leaf_type",
+                    &self
+                        .leaf_type_layout()
+                        .and_then(|l| l.ty())
+                        .and_then(|t| t.name()),
+                )
+                .finish()
+        }
+    }
+
+    impl<'a> BindingRange<'a> {
+        pub fn index(self) -> usize {
+            self.1 as _
+        }
+
+        pub fn binding_type(self) -> BindingType {
+            rcall!(spReflectionTypeLayout_getBindingRangeType(self.0, self.1))
+        }
+
+        pub fn binding_count(self) -> BindingCount {
+            BindingCount::from_raw(rcall!(spReflectionTypeLayout_getBindingRangeBindingCount(
+                self.0, self.1
+            )) as _)
+        }
+
+        pub fn is_specializable(self) -> bool {
+            rcall!(spReflectionTypeLayout_isBindingRangeSpecializable(
+                self.0, self.1
+            )) != 0
+        }
+
+        pub fn leaf_type_layout(self) -> Option<&'a TypeLayout> {
+            rcall!(
+                spReflectionTypeLayout_getBindingRangeLeafTypeLayout(self.0, self.1)
+                    as Option<&TypeLayout>
+            )
+        }
+
+        pub fn leaf_variable(self) -> Option<&'a Variable> {
+            rcall!(
+                spReflectionTypeLayout_getBindingRangeLeafVariable(self.0, self.1)
+                    as Option<&Variable>
+            )
+        }
+
+        pub fn image_format(self) -> ImageFormat {
+            rcall!(spReflectionTypeLayout_getBindingRangeImageFormat(
+                self.0, self.1
+            ))
+        }
+
+        /// Index into [`TypeLayout::descriptor_sets`] of the set this range belongs to.
+        pub fn descriptor_set_index(self) -> usize {
+            rcall!(spReflectionTypeLayout_getBindingRangeDescriptorSetIndex(
+                self.0, self.1
+            )) as _
+        }
+
+        pub fn first_descriptor_range_index(self) -> usize {
+            rcall!(spReflectionTypeLayout_getBindingRangeFirstDescriptorRangeIndex(self.0, self.1))
+                as _
+        }
+
+        pub fn descriptor_range_count(self) -> usize {
+            rcall!(spReflectionTypeLayout_getBindingRangeDescriptorRangeCount(
+                self.0, self.1
+            )) as _
+        }
+    }
+
+    /// A descriptor set (Vulkan) or register space (D3D12) required by a [`TypeLayout`].
+    #[derive(Clone, Copy)]
+    pub struct DescriptorSet<'a>(&'a TypeLayout, i64);
+
+    impl Debug for DescriptorSet<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.debug_struct("DescriptorSet")
+                .field("index", &self.1)
+                .field("space_offset", &self.space_offset())
+                .field(
+                    "descriptor_ranges",
+                    &self.descriptor_ranges().collect::<Vec<_>>(),
+                )
+                .finish()
+        }
+    }
+
+    impl<'a> DescriptorSet<'a> {
+        pub fn index(self) -> usize {
+            self.1 as _
+        }
+
+        /// Offset of this set relative to the space of the owning variable layout.
+        pub fn space_offset(self) -> usize {
+            rcall!(spReflectionTypeLayout_getDescriptorSetSpaceOffset(
+                self.0, self.1
+            )) as _
+        }
+
+        pub fn descriptor_range_count(self) -> usize {
+            rcall!(spReflectionTypeLayout_getDescriptorSetDescriptorRangeCount(
+                self.0, self.1
+            )) as _
+        }
+
+        pub fn descriptor_range_by_index(self, index: usize) -> Option<DescriptorRange> {
+            (index < self.descriptor_range_count()).then(|| self.descriptor_range(index as _))
+        }
+
+        pub fn descriptor_ranges(self) -> impl ExactSizeIterator<Item = DescriptorRange> {
+            (0..self.descriptor_range_count()).map(move |i| self.descriptor_range(i as _))
+        }
+
+        fn descriptor_range(self, index: i64) -> DescriptorRange {
+            let (layout, set) = (self.0, self.1);
+            DescriptorRange {
+                index_offset: rcall!(
+                    spReflectionTypeLayout_getDescriptorSetDescriptorRangeIndexOffset(
+                        layout, set, index
+                    )
+                ) as _,
+                descriptor_count: BindingCount::from_raw(rcall!(
+                    spReflectionTypeLayout_getDescriptorSetDescriptorRangeDescriptorCount(
+                        layout, set, index
+                    )
+                ) as _),
+                binding_type: rcall!(spReflectionTypeLayout_getDescriptorSetDescriptorRangeType(
+                    layout, set, index
+                )),
+                category: rcall!(
+                    spReflectionTypeLayout_getDescriptorSetDescriptorRangeCategory(
+                        layout, set, index
+                    )
+                ),
+            }
+        }
+    }
+
+    /// A range of descriptors inside a [`DescriptorSet`].
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct DescriptorRange {
+        /// Binding/register index of the first descriptor, relative to the set. May be
+        /// [`UNKNOWN_SIZE`].
+        pub index_offset: usize,
+        pub descriptor_count: BindingCount,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::reflect::snapshot::binding_type")
+        )]
+        pub binding_type: BindingType,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::reflect::snapshot::parameter_category")
+        )]
+        pub category: ParameterCategory,
+    }
+
+    /// A nested object (constant buffer, parameter block, existential value) inside a
+    /// [`TypeLayout`] that is bound through its own binding range.
+    #[derive(Clone, Copy)]
+    pub struct SubObjectRange<'a>(&'a TypeLayout, i64);
+
+    impl Debug for SubObjectRange<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.debug_struct("SubObjectRange")
+                .field("index", &self.1)
+                .field("binding_range", &self.binding_range())
+                .field("space_offset", &self.space_offset())
+                .finish()
+        }
+    }
+
+    impl<'a> SubObjectRange<'a> {
+        pub fn index(self) -> usize {
+            self.1 as _
+        }
+
+        pub fn binding_range_index(self) -> usize {
+            rcall!(spReflectionTypeLayout_getSubObjectRangeBindingRangeIndex(
+                self.0, self.1
+            )) as _
+        }
+
+        pub fn binding_range(self) -> Option<BindingRange<'a>> {
+            self.0.binding_range_by_index(self.binding_range_index())
+        }
+
+        /// Offset of the sub-object's space relative to the owning variable layout. May be
+        /// [`UNKNOWN_SIZE`].
+        pub fn space_offset(self) -> usize {
+            rcall!(spReflectionTypeLayout_getSubObjectRangeSpaceOffset(
+                self.0, self.1
+            )) as _
+        }
+
+        pub fn offset(self) -> Option<&'a VariableLayout> {
+            rcall!(
+                spReflectionTypeLayout_getSubObjectRangeOffset(self.0, self.1)
+                    as Option<&VariableLayout>
+            )
+        }
     }
 
     #[derive(Clone, Copy)]
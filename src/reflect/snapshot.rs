@@ -0,0 +1,566 @@
+//! Owned copies of the reflection tree.
+//!
+//! The borrowed reflection types in [`crate::reflect`] point into memory owned by the
+//! `ComponentType` they were queried from. A [`ReflectionSnapshot`] copies everything a host
+//! usually needs out of that tree in a single pass, so it can be cached, sent across threads or,
+//! with the `serde` feature, stored next to the compiled code.
+
+use super::{
+    BindingCount, DescriptorRange, EntryPoint, Shader, TypeLayout, TypeLayoutKind, VariableLayout,
+};
+use crate::{BindingType, ParameterCategory, ScalarType, Stage, TypeKind};
+use alloc::{boxed::Box, string::String, vec::Vec};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ReflectionSnapshot {
+    pub global_params: Option<VariableLayoutSnapshot>,
+    pub entry_points: Vec<EntryPointSnapshot>,
+    pub global_constant_buffer_binding: u64,
+    pub global_constant_buffer_size: usize,
+    pub bindless_space_index: u32,
+}
+
+impl ReflectionSnapshot {
+    pub fn new(shader: &Shader) -> Self {
+        Self {
+            global_params: shader
+                .global_params_var_layout()
+                .map(VariableLayoutSnapshot::new),
+            entry_points: shader.entry_points().map(EntryPointSnapshot::new).collect(),
+            global_constant_buffer_binding: shader.global_constant_buffer_binding(),
+            global_constant_buffer_size: shader.global_constant_buffer_size(),
+            bindless_space_index: shader.bindless_space_index(),
+        }
+    }
+
+    pub fn find_entry_point_by_name(&self, name: &str) -> Option<&EntryPointSnapshot> {
+        self.entry_points
+            .iter()
+            .find(|e| e.name.as_deref() == Some(name))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl ReflectionSnapshot {
+    pub fn to_json(&self) -> crate::Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    pub fn to_json_pretty(&self) -> crate::Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    pub fn from_json(json: &str) -> crate::Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Encode the snapshot in a compact binary form. Enumerations are stored by value rather
+    /// than by name, so the output is only readable by a build using the same Slang headers.
+    pub fn to_bytes(&self) -> crate::Result<Vec<u8>> {
+        Ok(postcard::to_allocvec(self)?)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
+        Ok(postcard::from_bytes(bytes)?)
+    }
+}
+
+impl From<&Shader> for ReflectionSnapshot {
+    fn from(shader: &Shader) -> Self {
+        Self::new(shader)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EntryPointSnapshot {
+    pub name: Option<String>,
+    pub name_override: Option<String>,
+    #[cfg_attr(feature = "serde", serde(with = "stage"))]
+    pub stage: Stage,
+    pub compute_thread_group_size: [u64; 3],
+    pub compute_wave_size: u64,
+    pub uses_any_sample_rate_input: bool,
+    pub has_default_constant_buffer: bool,
+    pub parameters: Vec<VariableLayoutSnapshot>,
+    pub var_layout: Option<VariableLayoutSnapshot>,
+    pub result_var_layout: Option<VariableLayoutSnapshot>,
+}
+
+impl EntryPointSnapshot {
+    pub fn new(entry_point: &EntryPoint) -> Self {
+        Self {
+            name: entry_point.name().map(Into::into),
+            name_override: entry_point.name_override().map(Into::into),
+            stage: entry_point.stage(),
+            compute_thread_group_size: entry_point.compute_thread_group_size(),
+            compute_wave_size: entry_point.compute_wave_size(),
+            uses_any_sample_rate_input: entry_point.uses_any_sample_rate_input(),
+            has_default_constant_buffer: entry_point.has_default_constant_buffer(),
+            parameters: entry_point
+                .parameters()
+                .map(VariableLayoutSnapshot::new)
+                .collect(),
+            var_layout: entry_point.var_layout().map(VariableLayoutSnapshot::new),
+            result_var_layout: entry_point
+                .result_var_layout()
+                .map(VariableLayoutSnapshot::new),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VariableLayoutSnapshot {
+    pub name: Option<String>,
+    pub semantic_name: Option<String>,
+    pub semantic_index: usize,
+    /// Offset and space for every category the variable's type consumes.
+    pub offsets: Vec<CategoryOffset>,
+    pub type_layout: Option<TypeLayoutSnapshot>,
+}
+
+impl VariableLayoutSnapshot {
+    pub fn new(var_layout: &VariableLayout) -> Self {
+        Self {
+            name: var_layout.name().map(Into::into),
+            semantic_name: var_layout.semantic_name().map(Into::into),
+            semantic_index: var_layout.semantic_index(),
+            offsets: var_layout
+                .categories()
+                .map(|category| CategoryOffset {
+                    category,
+                    offset: var_layout.offset(category),
+                    space: var_layout.binding_space_with_category(category),
+                })
+                .collect(),
+            type_layout: var_layout.type_layout().map(TypeLayoutSnapshot::new),
+        }
+    }
+
+    pub fn offset(&self, category: ParameterCategory) -> Option<&CategoryOffset> {
+        self.offsets.iter().find(|o| o.category == category)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CategoryOffset {
+    #[cfg_attr(feature = "serde", serde(with = "parameter_category"))]
+    pub category: ParameterCategory,
+    pub offset: usize,
+    pub space: usize,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TypeLayoutSnapshot {
+    /// Name of the underlying type, e.g. `float4` or the name of a struct.
+    pub name: Option<String>,
+    #[cfg_attr(feature = "serde", serde(with = "type_kind"))]
+    pub kind: TypeKind,
+    /// Only meaningful for scalar types; `ScalarType::None` otherwise.
+    #[cfg_attr(feature = "serde", serde(with = "scalar_type"))]
+    pub scalar_type: ScalarType,
+    pub sizes: Vec<CategorySize>,
+    pub fields: Vec<VariableLayoutSnapshot>,
+    /// Element count of arrays and vectors.
+    pub element_count: Option<usize>,
+    /// Row and column count of matrices.
+    pub matrix_dimensions: Option<[u32; 2]>,
+    /// Element layout of arrays and vectors.
+    pub element_type_layout: Option<Box<TypeLayoutSnapshot>>,
+    /// Element layout of constant buffers, parameter blocks and other single element containers.
+    pub element_var_layout: Option<Box<VariableLayoutSnapshot>>,
+    /// Layout of the container itself for single element containers.
+    pub container_var_layout: Option<Box<VariableLayoutSnapshot>>,
+    pub binding_ranges: Vec<BindingRangeSnapshot>,
+    pub descriptor_sets: Vec<DescriptorSetSnapshot>,
+    pub sub_object_ranges: Vec<SubObjectRangeSnapshot>,
+}
+
+impl TypeLayoutSnapshot {
+    pub fn new(type_layout: &TypeLayout) -> Self {
+        let ty = type_layout.ty();
+
+        let mut snapshot = Self {
+            name: ty.and_then(|ty| ty.name()).map(Into::into),
+            kind: ty.map_or(TypeKind::None, |ty| ty.kind()),
+            scalar_type: ScalarType::None,
+            sizes: type_layout
+                .categories()
+                .map(|category| CategorySize {
+                    category,
+                    size: type_layout.size(category),
+                    stride: type_layout.stride(category),
+                    alignment: type_layout.alignment(category),
+                })
+                .collect(),
+            fields: Vec::new(),
+            element_count: None,
+            matrix_dimensions: None,
+            element_type_layout: None,
+            element_var_layout: None,
+            container_var_layout: None,
+            binding_ranges: type_layout
+                .binding_ranges()
+                .map(|range| BindingRangeSnapshot {
+                    binding_type: range.binding_type(),
+                    binding_count: range.binding_count(),
+                    leaf_variable_name: range
+                        .leaf_variable()
+                        .and_then(|v| v.name())
+                        .map(Into::into),
+                    descriptor_set_index: range.descriptor_set_index(),
+                    first_descriptor_range_index: range.first_descriptor_range_index(),
+                    descriptor_range_count: range.descriptor_range_count(),
+                })
+                .collect(),
+            descriptor_sets: type_layout
+                .descriptor_sets()
+                .map(|set| DescriptorSetSnapshot {
+                    space_offset: set.space_offset(),
+                    descriptor_ranges: set.descriptor_ranges().collect(),
+                })
+                .collect(),
+            sub_object_ranges: type_layout
+                .sub_object_ranges()
+                .map(|range| SubObjectRangeSnapshot {
+                    binding_range_index: range.binding_range_index(),
+                    space_offset: range.space_offset(),
+                })
+                .collect(),
+        };
+
+        match type_layout.kind() {
+            Some(TypeLayoutKind::Struct(layout)) => {
+                snapshot.fields = layout.fields().map(VariableLayoutSnapshot::new).collect();
+            }
+            Some(TypeLayoutKind::Array(layout) | TypeLayoutKind::Vector(layout)) => {
+                snapshot.element_count = layout.element_count();
+                snapshot.element_type_layout = layout
+                    .element_type_layout()
+                    .map(|element| Box::new(Self::new(element)));
+            }
+            Some(TypeLayoutKind::Matrix(layout)) => {
+                snapshot.matrix_dimensions = layout
+                    .row_count()
+                    .zip(layout.column_count())
+                    .map(|(rows, columns)| [rows, columns]);
+            }
+            Some(TypeLayoutKind::Scalar(layout)) => {
+                snapshot.scalar_type = layout.scalar_type().unwrap_or(ScalarType::None);
+            }
+            Some(TypeLayoutKind::SingleElementContainer(layout, _)) => {
+                snapshot.element_var_layout = layout
+                    .element_var_layout()
+                    .map(|element| Box::new(VariableLayoutSnapshot::new(element)));
+                snapshot.container_var_layout = layout
+                    .container_var_layout()
+                    .map(|container| Box::new(VariableLayoutSnapshot::new(container)));
+            }
+            _ => {}
+        }
+
+        snapshot
+    }
+
+    pub fn size(&self, category: ParameterCategory) -> Option<&CategorySize> {
+        self.sizes.iter().find(|s| s.category == category)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CategorySize {
+    #[cfg_attr(feature = "serde", serde(with = "parameter_category"))]
+    pub category: ParameterCategory,
+    pub size: usize,
+    pub stride: usize,
+    pub alignment: i32,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BindingRangeSnapshot {
+    #[cfg_attr(feature = "serde", serde(with = "binding_type"))]
+    pub binding_type: BindingType,
+    pub binding_count: BindingCount,
+    pub leaf_variable_name: Option<String>,
+    pub descriptor_set_index: usize,
+    pub first_descriptor_range_index: usize,
+    pub descriptor_range_count: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DescriptorSetSnapshot {
+    pub space_offset: usize,
+    pub descriptor_ranges: Vec<DescriptorRange>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SubObjectRangeSnapshot {
+    pub binding_range_index: usize,
+    pub space_offset: usize,
+}
+
+/// Serializes a Slang enumeration by variant name in human readable formats and by value in
+/// binary ones.
+#[cfg(feature = "serde")]
+macro_rules! serde_enum {
+    ($module:ident: $ty:ty { $($variant:ident),* $(,)? }) => {
+        pub(crate) mod $module {
+            use super::*;
+            use alloc::format;
+            use serde::{Deserializer, Serializer, de::Error as _, ser::Error as _};
+
+            const VARIANTS: &[($ty, &str)] = &[$((<$ty>::$variant, stringify!($variant))),*];
+
+            pub(crate) fn serialize<S: Serializer>(
+                value: &$ty,
+                serializer: S,
+            ) -> core::result::Result<S::Ok, S::Error> {
+                let (value, name) = VARIANTS
+                    .iter()
+                    .find(|(v, _)| v == value)
+                    .ok_or_else(|| S::Error::custom(format!("unsupported value {value:?}")))?;
+                if serializer.is_human_readable() {
+                    serializer.serialize_str(name)
+                } else {
+                    serializer.serialize_u32(*value as u32)
+                }
+            }
+
+            pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> core::result::Result<$ty, D::Error> {
+                let found = if deserializer.is_human_readable() {
+                    let name = String::deserialize(deserializer)?;
+                    VARIANTS.iter().find(|(_, n)| *n == name)
+                } else {
+                    let value = u32::deserialize(deserializer)?;
+                    VARIANTS.iter().find(|(v, _)| *v as u32 == value)
+                };
+                found
+                    .map(|(v, _)| *v)
+                    .ok_or_else(|| D::Error::custom(concat!("invalid ", stringify!($ty))))
+            }
+        }
+    };
+}
+
+#[cfg(feature = "serde")]
+serde_enum!(parameter_category: ParameterCategory {
+    None,
+    Mixed,
+    ConstantBuffer,
+    ShaderResource,
+    UnorderedAccess,
+    VaryingInput,
+    VaryingOutput,
+    SamplerState,
+    Uniform,
+    DescriptorTableSlot,
+    SpecializationConstant,
+    PushConstantBuffer,
+    RegisterSpace,
+    Generic,
+    RayPayload,
+    HitAttributes,
+    CallablePayload,
+    ShaderRecord,
+    ExistentialTypeParam,
+    ExistentialObjectParam,
+    SubElementRegisterSpace,
+    Subpass,
+    MetalArgumentBufferElement,
+    MetalAttribute,
+    MetalPayload,
+});
+
+#[cfg(feature = "serde")]
+serde_enum!(stage: Stage {
+    None,
+    Vertex,
+    Hull,
+    Domain,
+    Geometry,
+    Fragment,
+    Compute,
+    RayGeneration,
+    Intersection,
+    AnyHit,
+    ClosestHit,
+    Miss,
+    Callable,
+    Mesh,
+    Amplification,
+    Dispatch,
+    Node,
+});
+
+#[cfg(feature = "serde")]
+serde_enum!(binding_type: BindingType {
+    Unknown,
+    Sampler,
+    Texture,
+    ConstantBuffer,
+    ParameterBlock,
+    TypedBuffer,
+    RawBuffer,
+    CombinedTextureSampler,
+    InputRenderTarget,
+    InlineUniformData,
+    RayTracingAccelerationStructure,
+    VaryingInput,
+    VaryingOutput,
+    ExistentialValue,
+    PushConstant,
+    MutableTeture,
+    MutableTypedBuffer,
+    MutableRawBuffer,
+});
+
+#[cfg(feature = "serde")]
+serde_enum!(scalar_type: ScalarType {
+    None,
+    Void,
+    Bool,
+    Int32,
+    Uint32,
+    Int64,
+    Uint64,
+    Float16,
+    Float32,
+    Float64,
+    Int8,
+    Uint8,
+    Int16,
+    Uint16,
+    Intptr,
+    Uintptr,
+    Bfloat16,
+    FloatE4m3,
+    FloatE5m2,
+});
+
+#[cfg(feature = "serde")]
+serde_enum!(type_kind: TypeKind {
+    None,
+    Struct,
+    Array,
+    Matrix,
+    Vector,
+    Scalar,
+    ConstantBuffer,
+    Resource,
+    SamplerState,
+    TextureBuffer,
+    ShaderStorageBuffer,
+    ParameterBlock,
+    GenericTypeParameter,
+    Interface,
+    OutputStream,
+    MeshOutput,
+    Specialized,
+    Feedback,
+    Pointer,
+    DynamicResource,
+    Enum,
+});
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    fn snapshot() -> ReflectionSnapshot {
+        let uniform = |offset| CategoryOffset {
+            category: ParameterCategory::Uniform,
+            offset,
+            space: 0,
+        };
+
+        ReflectionSnapshot {
+            global_params: Some(VariableLayoutSnapshot {
+                name: Some("params".into()),
+                semantic_name: None,
+                semantic_index: 0,
+                offsets: vec![uniform(16)],
+                type_layout: Some(TypeLayoutSnapshot {
+                    name: Some("float".into()),
+                    kind: TypeKind::Scalar,
+                    scalar_type: ScalarType::Float32,
+                    sizes: vec![CategorySize {
+                        category: ParameterCategory::Uniform,
+                        size: 4,
+                        stride: 4,
+                        alignment: 4,
+                    }],
+                    fields: Vec::new(),
+                    element_count: None,
+                    matrix_dimensions: None,
+                    element_type_layout: None,
+                    element_var_layout: None,
+                    container_var_layout: None,
+                    binding_ranges: vec![BindingRangeSnapshot {
+                        binding_type: BindingType::ConstantBuffer,
+                        binding_count: BindingCount::Finite(1),
+                        leaf_variable_name: None,
+                        descriptor_set_index: 0,
+                        first_descriptor_range_index: 0,
+                        descriptor_range_count: 1,
+                    }],
+                    descriptor_sets: vec![DescriptorSetSnapshot {
+                        space_offset: 0,
+                        descriptor_ranges: vec![DescriptorRange {
+                            index_offset: 0,
+                            descriptor_count: BindingCount::Finite(1),
+                            binding_type: BindingType::ConstantBuffer,
+                            category: ParameterCategory::ConstantBuffer,
+                        }],
+                    }],
+                    sub_object_ranges: Vec::new(),
+                }),
+            }),
+            entry_points: vec![EntryPointSnapshot {
+                name: Some("main".into()),
+                name_override: None,
+                stage: Stage::Compute,
+                compute_thread_group_size: [8, 8, 1],
+                compute_wave_size: 0,
+                uses_any_sample_rate_input: false,
+                has_default_constant_buffer: false,
+                parameters: Vec::new(),
+                var_layout: None,
+                result_var_layout: None,
+            }],
+            global_constant_buffer_binding: 0,
+            global_constant_buffer_size: 16,
+            bindless_space_index: 0,
+        }
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let snapshot = snapshot();
+        let json = snapshot.to_json().unwrap();
+        assert!(json.contains("\"Compute\""));
+        assert!(json.contains("\"ConstantBuffer\""));
+        assert_eq!(ReflectionSnapshot::from_json(&json).unwrap(), snapshot);
+    }
+
+    #[test]
+    fn binary_round_trip() {
+        let snapshot = snapshot();
+        let bytes = snapshot.to_bytes().unwrap();
+        assert!(bytes.len() < snapshot.to_json().unwrap().len());
+        assert_eq!(ReflectionSnapshot::from_bytes(&bytes).unwrap(), snapshot);
+    }
+}
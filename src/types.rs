@@ -3,12 +3,13 @@ mod com;
 pub use com::*;
 
 use crate::{
-    CompileTarget, DebugInfoLevel, FloatingPointMode, LineDirectiveMode, MatrixLayoutMode,
-    OptimizationLevel, ProfileId, SourceLanguage, Stage, reflect,
+    Blob, CapabilityId, CompileTarget, CompilerOptionName, DebugInfoLevel, Error,
+    FloatingPointMode, GlobalSession, LineDirectiveMode, MatrixLayoutMode, OptimizationLevel,
+    ProfileId, Result, SourceLanguage, Stage, Unknown, reflect,
 };
 use alloc::{boxed::Box, vec::Vec};
-use core::{marker::PhantomData, mem::zeroed, ptr::null};
-use std::ffi::{CString, c_char};
+use core::{fmt, marker::PhantomData, mem::zeroed, ptr::null, slice};
+use std::ffi::{CStr, CString, c_char};
 
 #[repr(C)]
 pub struct SessionDesc<'a> {
@@ -65,10 +66,105 @@ impl<'a> SessionDesc<'a> {
     }
 }
 
+/// A session description parsed by [`crate::GlobalSession::parse_command_line`]. Slang owns
+/// the targets, options and strings it points to, which live as long as this value.
+pub struct OwnedSessionDesc {
+    pub(crate) inner: sys::slang_SessionDesc,
+    pub(crate) _aux_allocation: Option<Unknown>,
+}
+
+impl OwnedSessionDesc {
+    /// Borrow as a [`SessionDesc`] to pass to [`crate::GlobalSession::create_session`].
+    pub fn session_desc(&self) -> SessionDesc<'_> {
+        SessionDesc {
+            inner: self.inner,
+            file_system: None,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn targets(&self) -> &[TargetDesc<'_>] {
+        // SAFETY: TargetDesc is a repr(C) slang_TargetDesc plus a zero sized marker.
+        unsafe {
+            raw_slice(
+                self.inner.targets as *const TargetDesc,
+                self.inner.targetCount as _,
+            )
+        }
+    }
+
+    pub fn options(&self) -> &[CompilerOptionEntry] {
+        unsafe {
+            raw_slice(
+                self.inner.compilerOptionEntries as *const CompilerOptionEntry,
+                self.inner.compilerOptionEntryCount as _,
+            )
+        }
+    }
+
+    pub fn search_paths(&self) -> impl ExactSizeIterator<Item = &str> {
+        unsafe { raw_slice(self.inner.searchPaths, self.inner.searchPathCount as _) }
+            .iter()
+            .map(|path| unsafe { c_str(*path) }.unwrap_or_default())
+    }
+
+    pub fn matrix_layout_mode(&self) -> MatrixLayoutMode {
+        self.inner.defaultMatrixLayoutMode
+    }
+}
+
+/// # Safety
+/// `ptr` must be null or point to `len` valid elements.
+unsafe fn raw_slice<'a, T>(ptr: *const T, len: usize) -> &'a [T] {
+    if ptr.is_null() {
+        &[]
+    } else {
+        unsafe { slice::from_raw_parts(ptr, len) }
+    }
+}
+
+/// # Safety
+/// `ptr` must be null or a nul terminated string.
+unsafe fn c_str<'a>(ptr: *const c_char) -> Option<&'a str> {
+    if ptr.is_null() {
+        None
+    } else {
+        unsafe { CStr::from_ptr(ptr) }.to_str().ok()
+    }
+}
+
+/// A single option, as found in a parsed [`OwnedSessionDesc`] or [`TargetDesc`].
+#[repr(transparent)]
+pub struct CompilerOptionEntry(sys::slang_CompilerOptionEntry);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CompilerOptionValue<'a> {
+    Int(i32, i32),
+    String(Option<&'a str>, Option<&'a str>),
+}
+
+impl CompilerOptionEntry {
+    pub fn name(&self) -> CompilerOptionName {
+        self.0.name
+    }
+
+    pub fn value(&self) -> CompilerOptionValue<'_> {
+        let value = &self.0.value;
+        match value.kind {
+            sys::slang_CompilerOptionValueKind::Int => {
+                CompilerOptionValue::Int(value.intValue0, value.intValue1)
+            }
+            sys::slang_CompilerOptionValueKind::String => unsafe {
+                CompilerOptionValue::String(c_str(value.stringValue0), c_str(value.stringValue1))
+            },
+        }
+    }
+}
+
 #[derive(Default)]
 pub struct CompilerOptions {
     strings: Vec<CString>,
-    options: Vec<sys::slang_CompilerOptionEntry>,
+    pub(crate) options: Vec<sys::slang_CompilerOptionEntry>,
 }
 
 unsafe impl Send for CompilerOptions {}
@@ -180,6 +276,8 @@ impl CompilerOptions {
 
     // Debugging
     option!(NoCodeGen, no_code_gen(enable: i32 bool));
+    option!(DumpIntermediates, dump_intermediates(enable: i32 bool));
+    option!(DumpIntermediatePrefix, dump_intermediate_prefix(prefix: &str));
 
     // Experimental
     option!(NoMangle, no_mangle(enable: i32 bool));
@@ -189,6 +287,61 @@ impl CompilerOptions {
     option!(SPIRVUnifiedDescriptorHeapStride, spirv_unified_descriptor_heap_stride(enable: i32 bool));
 
     option!(Capability, capability(cap: &str));
+
+    /// Add every capability in `capabilities`, see [`CapabilitySet`]. Pass the options to
+    /// [`TargetDesc::options`] to apply them to a single target.
+    pub fn capabilities(self, capabilities: &CapabilitySet) -> Self {
+        capabilities.ids.iter().fold(self, |options, id| {
+            options.push_ints(sys::slang_CompilerOptionName::Capability, id.0 as _, 0)
+        })
+    }
+}
+
+/// Capabilities checked against a [`GlobalSession`] when the set is built, so that a typo is
+/// an error instead of being silently ignored like [`CompilerOptions::capability`]. Add it to
+/// options with [`CompilerOptions::capabilities`].
+pub struct CapabilitySet {
+    ids: Vec<CapabilityId>,
+}
+
+impl CapabilitySet {
+    pub fn builder(global_session: &GlobalSession) -> CapabilitySetBuilder<'_> {
+        CapabilitySetBuilder {
+            global_session,
+            names: Vec::new(),
+        }
+    }
+
+    pub fn ids(&self) -> &[CapabilityId] {
+        &self.ids
+    }
+}
+
+pub struct CapabilitySetBuilder<'a> {
+    global_session: &'a GlobalSession,
+    names: Vec<String>,
+}
+
+impl CapabilitySetBuilder<'_> {
+    pub fn capability(mut self, name: &str) -> Self {
+        self.names.push(name.into());
+        self
+    }
+
+    /// Fails with [`Error::UnknownCapability`] on the first name Slang doesn't know.
+    pub fn build(self) -> Result<CapabilitySet> {
+        let ids = self
+            .names
+            .into_iter()
+            .map(|name| {
+                self.global_session
+                    .find_capability(&name)
+                    .ok_or(Error::UnknownCapability(name))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(CapabilitySet { ids })
+    }
 }
 
 #[repr(C)]
@@ -225,6 +378,23 @@ impl<'a> TargetDesc<'a> {
         self.inner.compilerOptionEntryCount = options.options.len() as _;
         self
     }
+
+    pub fn compile_target(&self) -> CompileTarget {
+        self.inner.format
+    }
+
+    pub fn profile_id(&self) -> ProfileId {
+        ProfileId(self.inner.profile)
+    }
+
+    pub fn option_entries(&self) -> &[CompilerOptionEntry] {
+        unsafe {
+            raw_slice(
+                self.inner.compilerOptionEntries as *const CompilerOptionEntry,
+                self.inner.compilerOptionEntryCount as _,
+            )
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -232,3 +402,91 @@ pub enum SpecializeArgument<'a> {
     Type(&'a reflect::Type),
     Expr(&'a str),
 }
+
+/// A hash computed by Slang, e.g. by [`crate::GlobalSession::session_desc_digest`] or
+/// [`crate::ComponentType::entry_point_hash`]. 128 or 256 bits wide, depending on the hash
+/// function Slang was built with.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Digest {
+    Bits128([u8; 16]),
+    Bits256([u8; 32]),
+}
+
+impl Digest {
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            Self::Bits128(bytes) => bytes,
+            Self::Bits256(bytes) => bytes,
+        }
+    }
+
+    /// Lowercase hexadecimal representation, the same as [`fmt::Display`].
+    pub fn to_hex(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl TryFrom<&[u8]> for Digest {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self> {
+        if let Ok(bytes) = value.try_into() {
+            Ok(Self::Bits128(bytes))
+        } else if let Ok(bytes) = value.try_into() {
+            Ok(Self::Bits256(bytes))
+        } else {
+            Err(Error::InvalidDigestLength(value.len()))
+        }
+    }
+}
+
+impl TryFrom<&Blob> for Digest {
+    type Error = Error;
+
+    fn try_from(value: &Blob) -> Result<Self> {
+        value.as_slice().try_into()
+    }
+}
+
+impl fmt::Display for Digest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_bytes()
+            .iter()
+            .try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
+impl fmt::Debug for Digest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Digest({self})")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn digest_formats_as_hex() {
+        let bytes: Vec<u8> = (0..16).map(|i| i * 0x11).collect();
+        let digest = Digest::try_from(&bytes[..]).unwrap();
+        assert_eq!(digest.to_hex(), "00112233445566778899aabbccddeeff");
+        assert_eq!(
+            format!("{digest:?}"),
+            "Digest(00112233445566778899aabbccddeeff)"
+        );
+        assert_eq!(digest.as_bytes(), bytes);
+    }
+
+    #[test]
+    fn digest_checks_length() {
+        assert!(matches!(
+            Digest::try_from(&[0u8; 32][..]),
+            Ok(Digest::Bits256(_))
+        ));
+        assert!(matches!(
+            Digest::try_from(&[0u8; 20][..]),
+            Err(Error::InvalidDigestLength(20))
+        ));
+    }
+}
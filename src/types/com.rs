@@ -1,4 +1,4 @@
-use crate::{Blob, Interface, Result, Unknown, Uuid};
+use crate::{Blob, Castable, Interface, Result, SharedLibrary, SharedLibraryLoader, Unknown, Uuid};
 use alloc::{boxed::Box, sync::Arc, vec::Vec};
 use core::ptr::{NonNull, null_mut};
 use std::{
@@ -270,6 +270,162 @@ impl<T: ISlangFileSystem> Com<T> {
     }
 }
 
+pub trait ISlangSharedLibrary: ISlangCastable {
+    /// Get the address of a symbol, or null if it isn't found.
+    fn find_symbol_address_by_name(&self, name: &str) -> *mut c_void;
+}
+
+impl ISlangUnknown for Box<dyn ISlangSharedLibrary> {
+    fn is_interface_compatible(&self, uuid: &Uuid) -> bool {
+        ISlangUnknown::is_interface_compatible(&**self, uuid)
+    }
+}
+
+impl ISlangCastable for Box<dyn ISlangSharedLibrary> {}
+
+impl ISlangSharedLibrary for Box<dyn ISlangSharedLibrary> {
+    fn find_symbol_address_by_name(&self, name: &str) -> *mut c_void {
+        ISlangSharedLibrary::find_symbol_address_by_name(&**self, name)
+    }
+}
+
+/// Forwards to a library loaded by Slang, e.g. through the default loader.
+impl ISlangUnknown for SharedLibrary {
+    fn is_interface_compatible(&self, uuid: &Uuid) -> bool {
+        Unknown::matches(uuid) || Castable::matches(uuid) || SharedLibrary::matches(uuid)
+    }
+}
+
+impl ISlangCastable for SharedLibrary {}
+
+impl ISlangSharedLibrary for SharedLibrary {
+    fn find_symbol_address_by_name(&self, name: &str) -> *mut c_void {
+        self.find_symbol(name)
+            .map_or(null_mut(), |symbol| symbol.as_ptr())
+    }
+}
+
+extern "C" fn slang_shared_library_find_symbol_address_by_name<T: ISlangSharedLibrary>(
+    this: *mut c_void,
+    name: *const c_char,
+) -> *mut c_void {
+    // SAFETY: this is always Com<T>
+    let com = unsafe { &mut *(this as *mut RawCom<T>) };
+
+    match unsafe { CStr::from_ptr(name) }.to_str() {
+        Ok(name) => com.value.find_symbol_address_by_name(name),
+        Err(_) => null_mut(),
+    }
+}
+
+impl<T: ISlangSharedLibrary> Com<T> {
+    const SHARED_LIBRARY_VTABLE: sys::ISlangSharedLibrary_vtable =
+        sys::ISlangSharedLibrary_vtable {
+            _base: Self::CASTABLE_VTABLE,
+            findSymbolAddressByName: slang_shared_library_find_symbol_address_by_name::<T>,
+        };
+
+    pub fn new_shared_library(value: T) -> Self {
+        Self::new(
+            &Self::SHARED_LIBRARY_VTABLE as *const sys::ISlangSharedLibrary_vtable as _,
+            value,
+        )
+        .expect("shared library vtable is invalid")
+    }
+}
+
+pub trait ISlangSharedLibraryLoader: ISlangUnknown {
+    /// Load a shared library. `path` is the platform independent name, e.g. `dxcompiler`
+    /// rather than `dxcompiler.dll` or `libdxcompiler.so`.
+    fn load_shared_library(&self, path: &str) -> Result<Box<dyn ISlangSharedLibrary>>;
+}
+
+impl ISlangUnknown for Box<dyn ISlangSharedLibraryLoader> {
+    fn is_interface_compatible(&self, uuid: &Uuid) -> bool {
+        ISlangUnknown::is_interface_compatible(&**self, uuid)
+    }
+}
+
+impl ISlangSharedLibraryLoader for Box<dyn ISlangSharedLibraryLoader> {
+    fn load_shared_library(&self, path: &str) -> Result<Box<dyn ISlangSharedLibrary>> {
+        ISlangSharedLibraryLoader::load_shared_library(&**self, path)
+    }
+}
+
+/// Forwards to a loader owned by Slang, e.g. the default one.
+impl ISlangUnknown for SharedLibraryLoader {
+    fn is_interface_compatible(&self, uuid: &Uuid) -> bool {
+        Unknown::matches(uuid) || SharedLibraryLoader::matches(uuid)
+    }
+}
+
+impl ISlangSharedLibraryLoader for SharedLibraryLoader {
+    fn load_shared_library(&self, path: &str) -> Result<Box<dyn ISlangSharedLibrary>> {
+        Ok(Box::new(SharedLibraryLoader::load_shared_library(
+            self, path,
+        )?))
+    }
+}
+
+extern "C" fn slang_shared_library_loader_load_shared_library<T: ISlangSharedLibraryLoader>(
+    this: *mut c_void,
+    path: *const c_char,
+    out_shared_library: *mut *mut sys::ISlangSharedLibrary,
+) -> sys::SlangResult {
+    // SAFETY: this is always Com<T>
+    let com = unsafe { &mut *(this as *mut RawCom<T>) };
+    unsafe { *out_shared_library = null_mut() };
+
+    let Ok(path) = unsafe { CStr::from_ptr(path) }.to_str() else {
+        return -1;
+    };
+
+    match com.value.load_shared_library(path) {
+        Ok(shared_library) => unsafe {
+            let shared_library = Com::new_shared_library(shared_library).into_raw();
+            let vtable =
+                &*((&*shared_library).vtable.as_ptr() as *const sys::ISlangUnknown__bindgen_vtable);
+            (vtable.ISlangUnknown_addRef)(shared_library as _);
+            *out_shared_library = shared_library as _;
+            0
+        },
+        Err(err) => err.into(),
+    }
+}
+
+impl<T: ISlangSharedLibraryLoader> Com<T> {
+    const SHARED_LIBRARY_LOADER_VTABLE: sys::ISlangSharedLibraryLoader_vtable =
+        sys::ISlangSharedLibraryLoader_vtable {
+            _base: Self::UNKNOWN_VTABLE,
+            loadSharedLibrary: slang_shared_library_loader_load_shared_library::<T>,
+        };
+
+    pub fn new_shared_library_loader(value: T) -> Self {
+        Self::new(
+            &Self::SHARED_LIBRARY_LOADER_VTABLE as *const sys::ISlangSharedLibraryLoader_vtable
+                as _,
+            value,
+        )
+        .expect("shared library loader vtable is invalid")
+    }
+}
+
+/// Receives the diagnostics of every call made through a session, including warnings and
+/// notes from calls that succeed. See [`crate::Session::register_diagnostic_sink`].
+///
+/// Slang has no API to install an `ISlangWriter` on an `ISession`, so sinks are not backed by a
+/// writer. They are called directly with the text of the diagnostics blob each call returns.
+pub trait DiagnosticSink: Send + Sync {
+    /// `diagnostics` holds every message reported by one call, in Slang's usual text format.
+    fn diagnostics(&self, diagnostics: &str);
+}
+
+impl<F: Fn(&str) + Send + Sync> DiagnosticSink for F {
+    fn diagnostics(&self, diagnostics: &str) {
+        self(diagnostics)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -317,4 +473,51 @@ mod tests {
         let blob = Com::new_blob(buf);
         drop(blob);
     }
+
+    #[test]
+    fn shared_library_loader_is_working() {
+        static SYMBOL: u32 = 42;
+
+        struct Library;
+
+        impl ISlangUnknown for Library {
+            fn is_interface_compatible(&self, uuid: &Uuid) -> bool {
+                Unknown::matches(uuid) || SharedLibrary::matches(uuid)
+            }
+        }
+
+        impl ISlangCastable for Library {}
+
+        impl ISlangSharedLibrary for Library {
+            fn find_symbol_address_by_name(&self, name: &str) -> *mut c_void {
+                match name {
+                    "answer" => &SYMBOL as *const u32 as _,
+                    _ => null_mut(),
+                }
+            }
+        }
+
+        struct Loader;
+
+        impl ISlangUnknown for Loader {}
+
+        impl ISlangSharedLibraryLoader for Loader {
+            fn load_shared_library(&self, path: &str) -> Result<Box<dyn ISlangSharedLibrary>> {
+                match path {
+                    "test" => Ok(Box::new(Library)),
+                    _ => Err(crate::Error::Code(-1)),
+                }
+            }
+        }
+
+        let loader = Com::new_shared_library_loader(Loader).into_unknown();
+        let loader = SharedLibraryLoader(loader);
+
+        assert!(loader.load_shared_library("missing").is_err());
+
+        let library = loader.load_shared_library("test").unwrap();
+        let answer = library.find_symbol("answer").unwrap();
+        assert_eq!(unsafe { *(answer.as_ptr() as *const u32) }, 42);
+        assert!(library.find_symbol("question").is_none());
+    }
 }
@@ -40,6 +40,7 @@ fn main() {
         .derive_copy(true)
         .allowlist_function("spReflection.*")
         .allowlist_function("spComputeStringHash")
+        .allowlist_function("spGetBuildTagString")
         .allowlist_function("slang_.*")
         .allowlist_type("slang.*")
         .allowlist_var("SLANG_.*")
@@ -75,6 +76,8 @@ impl bindgen::callbacks::ParseCallbacks for ParseCallback {
         let mut map = std::collections::HashMap::new();
         map.insert("SlangMatrixLayoutMode", "SlangMatrixLayout");
         map.insert("SlangCompileTarget", "Slang");
+        map.insert("SlangModifierID", "SlangModifier");
+        map.insert("SlangReflectionGenericArgType", "SlangGenericArg");
 
         let trim = map.get(enum_name).unwrap_or(&enum_name);
         let new_variant_name = pascal_case_from_snake_case(original_variant_name);
@@ -35,6 +35,38 @@ pub struct ISlangFileSystem_vtable {
 	pub loadFile: fp!((path: *const c_char, outBlob: *mut *mut ISlangBlob) -> SlangResult),
 }
 
+#[repr(C)]
+pub struct ISlangFileSystemExt_vtable {
+	pub _base: ISlangFileSystem_vtable,
+
+	pub getFileUniqueIdentity: fp!((path: *const c_char, outUniqueIdentity: *mut *mut ISlangBlob) -> SlangResult),
+	pub calcCombinedPath: fp!((
+		fromPathType: SlangPathType,
+		fromPath: *const c_char,
+		path: *const c_char,
+		pathOut: *mut *mut ISlangBlob
+	) -> SlangResult),
+	pub getPathType: fp!((path: *const c_char, pathTypeOut: *mut SlangPathType) -> SlangResult),
+	pub getPath: fp!((kind: PathKind, path: *const c_char, outPath: *mut *mut ISlangBlob) -> SlangResult),
+	pub clearCache: fp!(()),
+	pub enumeratePathContents: fp!((path: *const c_char, callback: FileSystemContentsCallBack, userData: *mut c_void) -> SlangResult),
+	pub getOSPathKind: fp!(() -> OSPathKind),
+}
+
+#[repr(C)]
+pub struct ISlangSharedLibrary_vtable {
+	pub _base: ISlangCastable_vtable,
+
+	pub findSymbolAddressByName: fp!((name: *const c_char) -> *mut c_void),
+}
+
+#[repr(C)]
+pub struct ISlangSharedLibraryLoader_vtable {
+	pub _base: ISlangUnknown__bindgen_vtable,
+
+	pub loadSharedLibrary: fp!((path: *const c_char, sharedLibraryOut: *mut *mut ISlangSharedLibrary) -> SlangResult),
+}
+
 #[repr(C)]
 pub struct IGlobalSession_vtable {
 	pub _base: ISlangUnknown__bindgen_vtable,
@@ -69,7 +101,7 @@ pub struct IGlobalSession_vtable {
 	pub getSessionDescDigest: fp!((sessionDesc: *mut slang_SessionDesc, outBlob: *mut *mut ISlangBlob) -> SlangResult),
 	pub compileBuiltinModule: fp!((module: slang_BuiltinModuleName, flags: slang_CompileCoreModuleFlags) -> SlangResult),
 	pub loadBuiltinModule: fp!((module: slang_BuiltinModuleName, moduleData: *const c_void, sizeInBytes: usize) -> SlangResult),
-	pub saveBuiltinModule: fp!((module: slang_BuiltinModuleName, archiveType: SlangArchiveType, outBlob: *mut *mut ISlangBlob)),
+	pub saveBuiltinModule: fp!((module: slang_BuiltinModuleName, archiveType: SlangArchiveType, outBlob: *mut *mut ISlangBlob) -> SlangResult),
 }
 
 #[repr(C)]
@@ -95,7 +127,7 @@ pub struct ISession_vtable {
 		specializationArgs: *const slang_SpecializationArg,
 		specializationArgCount: SlangInt,
 		outDiagnostics: *mut *mut ISlangBlob
-	)),
+	) -> *mut slang_TypeReflection),
 	pub getTypeLayout: fp!((
 		type_: *mut slang_TypeReflection,
 		targetIndex: SlangInt,
@@ -0,0 +1,54 @@
+//! Fixtures shared by the integration tests. Each test binary only uses some of them.
+#![allow(dead_code)]
+
+use slang::{ComponentType, GlobalSession, Module, Session, SessionDesc, TargetDesc};
+
+/// A session of `global` compiling to `targets`.
+pub fn session_with_targets(global: &GlobalSession, targets: &[TargetDesc]) -> Session {
+    global
+        .create_session(&SessionDesc::default().targets(targets))
+        .unwrap()
+}
+
+/// A session of `global` compiling to SPIR-V 1.5, which Slang emits without a downstream
+/// compiler.
+pub fn spirv_session_in(global: &GlobalSession) -> Session {
+    let targets = [TargetDesc::default()
+        .format(slang::CompileTarget::Spirv)
+        .profile(global.find_profile("spirv_1_5"))];
+    session_with_targets(global, &targets)
+}
+
+/// A SPIR-V 1.5 session on a global session of its own.
+pub fn spirv_session() -> Session {
+    spirv_session_in(&GlobalSession::new().unwrap())
+}
+
+/// A session without targets, for tests that only look at modules.
+pub fn plain_session() -> Session {
+    GlobalSession::new()
+        .unwrap()
+        .create_session(&SessionDesc::default())
+        .unwrap()
+}
+
+/// Load `source` as the module `name`, from the file `<name>.slang`.
+pub fn load(session: &Session, name: &str, source: &str) -> Module {
+    session
+        .load_module_from_source_string(name, &format!("{name}.slang"), source)
+        .unwrap()
+}
+
+/// The module `name` composed with its `main` entry point, not linked yet.
+pub fn compose(session: &Session, name: &str, source: &str) -> ComponentType {
+    let module = load(session, name, source);
+    let entry_point = module.find_entry_point_by_name("main").unwrap();
+    session
+        .create_composite_component_type(&[module.into(), entry_point.into()])
+        .unwrap()
+}
+
+/// [`compose`], then link.
+pub fn link(session: &Session, name: &str, source: &str) -> ComponentType {
+    compose(session, name, source).link().unwrap()
+}
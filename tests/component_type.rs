@@ -0,0 +1,217 @@
+mod common;
+
+use common::{compose, link, load, plain_session, session_with_targets, spirv_session};
+use slang::{GlobalSession, TargetDesc};
+
+#[test]
+fn link_with_options() {
+    use slang::{CompilerOptions, DebugInfoLevel, OptimizationLevel};
+
+    let session = spirv_session();
+    let program = compose(
+        &session,
+        "reflection_json",
+        include_str!("../examples/reflection_json.slang"),
+    );
+
+    let debug = program
+        .link_with_options(
+            &CompilerOptions::default()
+                .debug_information(DebugInfoLevel::Maximal)
+                .optimization(OptimizationLevel::None),
+        )
+        .unwrap();
+    let release = program
+        .link_with_options(&CompilerOptions::default().optimization(OptimizationLevel::Maximal))
+        .unwrap();
+
+    let debug_code = debug.entry_point_code(0, 0).unwrap();
+    let release_code = release.entry_point_code(0, 0).unwrap();
+    assert_ne!(debug_code.as_slice(), release_code.as_slice());
+}
+
+const UNUSED_BINDING: &str = r#"
+RWStructuredBuffer<float> used;
+RWStructuredBuffer<float> unused;
+
+[shader("compute")]
+[numthreads(64, 1, 1)]
+void main(uint3 id: SV_DispatchThreadID) {
+    used[id.x] = 1.0;
+}
+
+[shader("compute")]
+[numthreads(64, 1, 1)]
+void other(uint3 id: SV_DispatchThreadID) {
+    unused[id.x] = 2.0;
+}
+"#;
+
+#[test]
+fn entry_point_metadata_and_hash() {
+    use slang::ParameterCategory;
+
+    let session = spirv_session();
+    let module = load(&session, "unused", UNUSED_BINDING);
+    let main = module.find_entry_point_by_name("main").unwrap();
+    let other = module.find_entry_point_by_name("other").unwrap();
+    let program = session
+        .create_composite_component_type(&[module.into(), main.into(), other.into()])
+        .unwrap()
+        .link()
+        .unwrap();
+
+    let metadata = program.entry_point_metadata(0, 0).unwrap();
+    assert!(
+        metadata
+            .is_parameter_location_used(ParameterCategory::DescriptorTableSlot, 0, 0)
+            .unwrap()
+    );
+    assert!(
+        !metadata
+            .is_parameter_location_used(ParameterCategory::DescriptorTableSlot, 0, 1)
+            .unwrap()
+    );
+
+    let main_hash = program.entry_point_hash(0, 0).unwrap();
+    let other_hash = program.entry_point_hash(1, 0).unwrap();
+    assert!(!main_hash.as_slice().is_empty());
+    assert_ne!(main_hash.as_slice(), other_hash.as_slice());
+    assert_eq!(
+        main_hash.as_slice(),
+        program.entry_point_hash(0, 0).unwrap().as_slice()
+    );
+
+    // The emitted SPIR-V is one of the files at the root of the result file system.
+    let file_system = program.result_as_file_system(0, 0).unwrap();
+    let files: Vec<_> = file_system
+        .enumerate_path_contents(".")
+        .unwrap()
+        .into_iter()
+        .filter(|(path_type, _)| *path_type == slang::PathType::File)
+        .map(|(_, name)| name)
+        .collect();
+    let code = program.entry_point_code(0, 0).unwrap();
+    let spirv = files
+        .iter()
+        .map(|name| file_system.load_file(name).unwrap())
+        .find(|file| file.as_slice() == code.as_slice())
+        .expect("no file matching the entry point code");
+    assert!(!spirv.as_slice().is_empty());
+}
+
+const LEGACY_HLSL: &str = r#"
+RWStructuredBuffer<float> output;
+
+[numthreads(64, 1, 1)]
+void CSMain(uint3 id : SV_DispatchThreadID) {
+    output[id.x] = float(id.x);
+}
+"#;
+
+#[test]
+fn undecorated_and_renamed_entry_points() {
+    let session = spirv_session();
+    let module = session
+        .load_module_from_source_string("legacy", "legacy.hlsl", LEGACY_HLSL)
+        .unwrap();
+
+    assert!(module.find_entry_point_by_name("CSMain").is_err());
+    let entry_point = module
+        .find_and_check_entry_point("CSMain", slang::Stage::Compute)
+        .unwrap();
+
+    // Missing functions report diagnostics.
+    assert!(matches!(
+        module.find_and_check_entry_point("Missing", slang::Stage::Compute),
+        Err(slang::Error::Blob(_))
+    ));
+
+    let first = entry_point.rename_entry_point("first").unwrap();
+    let second = entry_point.rename_entry_point("second").unwrap();
+    let program = session
+        .create_composite_component_type(&[module.into(), first, second])
+        .unwrap()
+        .link()
+        .unwrap();
+
+    let shader = program.layout(0).unwrap();
+    let names: Vec<_> = shader
+        .entry_points()
+        .map(|e| e.name_override().or(e.name()).unwrap())
+        .collect();
+    assert_eq!(names, ["first", "second"]);
+    program.target_code(0).unwrap();
+}
+
+#[test]
+fn disassemble_and_back_references() {
+    let session = plain_session();
+    let module = load(&session, "legacy", LEGACY_HLSL);
+
+    let ir = module.disassemble().unwrap();
+    assert!(ir.as_str().unwrap().contains("CSMain"));
+
+    // Going back up the ownership chain lands on the same objects.
+    let owner = module.session();
+    assert_eq!(owner.loaded_module_count(), session.loaded_module_count());
+    assert!(
+        owner
+            .loaded_modules()
+            .any(|m| m.name().ok() == Some("legacy"))
+    );
+
+    let component: slang::ComponentType = module.into();
+    let global_again = component.session().global_session();
+    assert!(!global_again.find_profile("spirv_1_5").is_unknown());
+}
+
+const CPU_KERNEL: &str = r#"
+RWStructuredBuffer<int> output;
+
+[shader("compute")]
+[numthreads(4, 1, 1)]
+void main(uint3 id: SV_DispatchThreadID) {
+    output[id.x] = int(id.x) * 2;
+}
+"#;
+
+#[test]
+fn host_callable_compute_kernel() {
+    use slang::{ComputeFunc, ComputeVaryingInput};
+
+    // Layout of a `RWStructuredBuffer` on CPU targets.
+    #[repr(C)]
+    struct Buffer {
+        data: *mut i32,
+        count: usize,
+    }
+
+    let global = GlobalSession::new().unwrap();
+    let targets = [TargetDesc::default().format(slang::CompileTarget::ShaderHostCallable)];
+    let session = session_with_targets(&global, &targets);
+    let program = link(&session, "cpu_kernel", CPU_KERNEL);
+
+    let library = program.entry_point_host_callable(0, 0).unwrap();
+    assert!(library.find_symbol("does_not_exist").is_none());
+    let main: ComputeFunc = unsafe { library.find_func_by_name("main") }.unwrap();
+
+    let mut data = [0i32; 8];
+    let mut global_params = Buffer {
+        data: data.as_mut_ptr(),
+        count: data.len(),
+    };
+    let mut varying = ComputeVaryingInput {
+        start_group_id: [0, 0, 0],
+        end_group_id: [2, 1, 1],
+    };
+    unsafe {
+        main(
+            &mut varying,
+            core::ptr::null_mut(),
+            &mut global_params as *mut Buffer as _,
+        )
+    };
+
+    assert_eq!(data, [0, 2, 4, 6, 8, 10, 12, 14]);
+}
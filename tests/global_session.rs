@@ -0,0 +1,325 @@
+mod common;
+
+use common::{link, load, session_with_targets, spirv_session, spirv_session_in};
+use slang::{CompileTarget, GlobalSession, PassThrough, SessionDesc, SourceLanguage, TargetDesc};
+
+const COMPUTE: &str = r#"
+RWStructuredBuffer<float> output;
+
+[shader("compute")]
+[numthreads(1, 1, 1)]
+void main(uint3 id: SV_DispatchThreadID) {
+    output[id.x] = 1.0;
+}
+"#;
+
+#[test]
+fn downstream_compiler_configuration() {
+    let global = GlobalSession::new().unwrap();
+
+    // SPIR-V is emitted directly, without a downstream compiler.
+    global
+        .check_compile_target_support(CompileTarget::Spirv)
+        .unwrap();
+    global
+        .check_pass_through_support(PassThrough::None)
+        .unwrap();
+
+    // DXIL always goes through DXC, so both checks agree on whether it is available.
+    let dxc = global.check_pass_through_support(PassThrough::Dxc);
+    assert_eq!(
+        dxc.is_ok(),
+        global
+            .check_compile_target_support(CompileTarget::Dxil)
+            .is_ok()
+    );
+    if dxc.is_err() {
+        eprintln!("DXC is not available, skipping DXIL compilation");
+    } else {
+        let targets = [TargetDesc::default()
+            .format(CompileTarget::Dxil)
+            .profile(global.find_profile("sm_6_0"))];
+        let session = session_with_targets(&global, &targets);
+        let program = link(&session, "dxil", COMPUTE);
+        assert!(
+            !program
+                .entry_point_code(0, 0)
+                .unwrap()
+                .as_slice()
+                .is_empty()
+        );
+    }
+
+    global
+        .set_default_downstream_compiler(SourceLanguage::Hlsl, PassThrough::Dxc)
+        .unwrap();
+    assert_eq!(
+        global.default_downstream_compiler(SourceLanguage::Hlsl),
+        PassThrough::Dxc
+    );
+
+    assert!(
+        global
+            .set_downstream_compiler_path(PassThrough::Dxc, "a\0b")
+            .is_err()
+    );
+
+    global.set_downstream_compiler_for_transition(
+        CompileTarget::Hlsl,
+        CompileTarget::Dxil,
+        PassThrough::Dxc,
+    );
+}
+
+#[test]
+fn rust_shared_library_loader() {
+    use slang::{ISlangSharedLibrary, ISlangSharedLibraryLoader, ISlangUnknown};
+    use std::sync::{Arc, Mutex};
+
+    // Refuses every library, but records which ones were asked for.
+    struct Loader(Arc<Mutex<Vec<String>>>);
+
+    impl ISlangUnknown for Loader {}
+
+    impl ISlangSharedLibraryLoader for Loader {
+        fn load_shared_library(&self, path: &str) -> slang::Result<Box<dyn ISlangSharedLibrary>> {
+            self.0.lock().unwrap().push(path.to_owned());
+            Err(slang::Error::Code(-1))
+        }
+    }
+
+    let requested = Arc::new(Mutex::new(Vec::new()));
+    let global = GlobalSession::new().unwrap();
+    global.set_shared_library_loader(Loader(requested.clone()));
+
+    // The loader read back from Slang calls into the Rust one.
+    let loader = global.shared_library_loader().unwrap();
+    assert!(loader.load_shared_library("example").is_err());
+    assert_eq!(*requested.lock().unwrap(), ["example"]);
+
+    // Downstream compilers are loaded through it as well, so glslang can't be found.
+    assert!(
+        global
+            .check_pass_through_support(PassThrough::Glslang)
+            .is_err()
+    );
+    assert!(requested.lock().unwrap().len() > 1);
+
+    global.reset_shared_library_loader();
+}
+
+#[test]
+fn language_prelude_and_builtins() {
+    let global = GlobalSession::new().unwrap();
+
+    let prelude = "// project prelude\n";
+    global
+        .set_language_prelude(SourceLanguage::Cpp, prelude)
+        .unwrap();
+    let stored = global.language_prelude(SourceLanguage::Cpp).unwrap();
+    assert_eq!(stored.as_str().unwrap(), prelude);
+
+    global
+        .add_builtins(
+            "project_builtins.slang",
+            "public float project_scale(float x) { return x * 2.0; }",
+        )
+        .unwrap();
+
+    // Interior NULs are rejected instead of panicking.
+    assert!(
+        global
+            .set_language_prelude(SourceLanguage::Cpp, "a\0b")
+            .is_err()
+    );
+    assert!(global.add_builtins("nul.slang", "a\0b").is_err());
+
+    let session = spirv_session_in(&global);
+    let module = load(
+        &session,
+        "uses_builtins",
+        "[shader(\"compute\")] [numthreads(1, 1, 1)]
+        void main(uniform RWStructuredBuffer<float> output) {
+            output[0] = project_scale(1.0);
+        }",
+    );
+    module.find_entry_point_by_name("main").unwrap();
+}
+
+#[test]
+fn core_module_cache() {
+    use slang::ArchiveType;
+
+    let path =
+        std::env::temp_dir().join(format!("slang-rs-core-module-{}.bin", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    // The first build compiles and saves the core module, the second loads it.
+    GlobalSession::builder()
+        .core_module_cache(&path)
+        .build()
+        .unwrap();
+    let cached = std::fs::read(&path).unwrap();
+    assert!(!cached.is_empty());
+
+    let global = GlobalSession::builder()
+        .core_module_cache(&path)
+        .build()
+        .unwrap();
+    global.create_session(&SessionDesc::default()).unwrap();
+
+    let saved = global.save_core_module(ArchiveType::RiffDeflate).unwrap();
+    let global = GlobalSession::builder()
+        .core_module(saved.as_slice())
+        .build()
+        .unwrap();
+    global.create_session(&SessionDesc::default()).unwrap();
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn unwritable_core_module_cache() {
+    let file =
+        std::env::temp_dir().join(format!("slang-rs-not-a-directory-{}", std::process::id()));
+    std::fs::write(&file, b"").unwrap();
+
+    // The cache can't be created below a file, but the session is still usable.
+    let global = GlobalSession::builder()
+        .core_module_cache(file.join("core-module.bin"))
+        .build()
+        .unwrap();
+    global
+        .create_session(&slang::SessionDesc::default())
+        .unwrap();
+
+    std::fs::remove_file(&file).unwrap();
+}
+
+#[test]
+fn embedded_core_module() {
+    let global = GlobalSession::builder()
+        .embedded_core_module()
+        .build()
+        .unwrap();
+    global
+        .create_session(&slang::SessionDesc::default())
+        .unwrap();
+}
+
+#[test]
+fn parse_command_line() {
+    let global = GlobalSession::new().unwrap();
+
+    let desc = global
+        .parse_command_line(&["-target", "spirv", "-profile", "spirv_1_5", "-I", "shaders"])
+        .unwrap();
+    assert_eq!(desc.targets().len(), 1);
+    assert_eq!(desc.targets()[0].compile_target(), CompileTarget::Spirv);
+    assert!(desc.search_paths().any(|path| path == "shaders"));
+
+    global.create_session(&desc.session_desc()).unwrap();
+
+    assert!(global.parse_command_line(&["-not-a-real-flag"]).is_err());
+}
+
+#[test]
+fn session_desc_digest() {
+    use slang::{CompilerOptions, Digest, OptimizationLevel};
+
+    let global = GlobalSession::new().unwrap();
+    let targets = [TargetDesc::default()
+        .format(CompileTarget::Spirv)
+        .profile(global.find_profile("spirv_1_5"))];
+    let digest = |options: &CompilerOptions| {
+        let desc = SessionDesc::default().targets(&targets).options(options);
+        Digest::try_from(&global.session_desc_digest(&desc).unwrap()).unwrap()
+    };
+
+    let default = digest(&CompilerOptions::default());
+    assert_eq!(default, digest(&CompilerOptions::default()));
+    assert_ne!(
+        default,
+        digest(&CompilerOptions::default().optimization(OptimizationLevel::Maximal))
+    );
+    assert_eq!(default.to_hex().len(), default.as_bytes().len() * 2);
+}
+
+#[test]
+fn elapsed_time_and_build_tag() {
+    let global = GlobalSession::new().unwrap();
+    assert!(!slang::build_tag().is_empty());
+    assert_eq!(global.build_tag(), slang::build_tag());
+
+    let session = spirv_session_in(&global);
+    link(&session, "timed", COMPUTE).target_code(0).unwrap();
+
+    // SPIR-V needs no downstream compiler, so all of the time is Slang's own.
+    let timings = global.elapsed_time();
+    assert!(timings.total > std::time::Duration::ZERO);
+    assert_eq!(timings.downstream, std::time::Duration::ZERO);
+
+    let later = global.elapsed_time();
+    assert_eq!((later - timings).downstream, std::time::Duration::ZERO);
+}
+
+#[test]
+fn capability_sets() {
+    use slang::{CapabilitySet, CompilerOptions, Error};
+
+    let global = GlobalSession::new().unwrap();
+    assert!(global.find_capability("spirv_1_5").is_some());
+    assert!(global.find_capability("spirv_1_55").is_none());
+
+    let err = CapabilitySet::builder(&global)
+        .capability("spirv_1_5")
+        .capability("spirv_1_55")
+        .build()
+        .err()
+        .unwrap();
+    assert!(matches!(err, Error::UnknownCapability(name) if name == "spirv_1_55"));
+
+    let capabilities = CapabilitySet::builder(&global)
+        .capability("spirv_1_5")
+        .build()
+        .unwrap();
+    assert_eq!(capabilities.ids().len(), 1);
+
+    // Capabilities are added alongside other options rather than replacing them.
+    let options = CompilerOptions::default()
+        .optimization(slang::OptimizationLevel::High)
+        .capabilities(&capabilities);
+    let targets = [TargetDesc::default()
+        .format(CompileTarget::Spirv)
+        .options(&options)];
+    session_with_targets(&global, &targets);
+}
+
+#[test]
+fn diagnostic_sink_receives_warnings() {
+    use std::sync::{Arc, Mutex};
+
+    let session = spirv_session();
+
+    let received = Arc::new(Mutex::new(String::new()));
+    let registration = session.register_diagnostic_sink({
+        let received = received.clone();
+        move |diagnostics: &str| received.lock().unwrap().push_str(diagnostics)
+    });
+
+    // The truncating conversion is a warning, so loading still succeeds.
+    let module = load(
+        &session,
+        "warns",
+        "[shader(\"compute\")] [numthreads(1, 1, 1)]
+        void main(uniform RWStructuredBuffer<int> output) { int x = 1.5; output[0] = x; }",
+    );
+    assert!(received.lock().unwrap().contains("warning"));
+
+    drop(registration);
+    received.lock().unwrap().clear();
+    load(&session, "warns_again", "void helper() { int x = 1.5; }");
+    assert!(received.lock().unwrap().is_empty());
+
+    module.find_entry_point_by_name("main").unwrap();
+}
@@ -0,0 +1,864 @@
+mod common;
+
+use common::{compose, link, load, plain_session, spirv_session};
+
+#[cfg(feature = "serde")]
+#[test]
+fn to_json_matches_traversal() {
+    use slang::reflect::ReflectionSnapshot;
+
+    let session = spirv_session();
+    let program = link(
+        &session,
+        "reflection_json",
+        include_str!("../examples/reflection_json.slang"),
+    );
+    let shader = program.layout(0).unwrap();
+    let json = shader.to_json_value().unwrap();
+    let snapshot = ReflectionSnapshot::new(shader);
+
+    assert_eq!(
+        json["entryPoints"].as_array().unwrap().len(),
+        snapshot.entry_points.len()
+    );
+    for (json, entry_point) in json["entryPoints"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .zip(&snapshot.entry_points)
+    {
+        assert_eq!(json["name"].as_str(), entry_point.name.as_deref());
+    }
+
+    let parameters: Vec<_> = json["parameters"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|p| p["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(parameters, ["constants", "image", "linear_sampler"]);
+
+    // The blob is valid UTF-8 and identical to the parsed form.
+    let blob = shader.to_json().unwrap();
+    let reparsed: serde_json::Value = serde_json::from_str(blob.as_str().unwrap()).unwrap();
+    assert_eq!(reparsed, json);
+}
+
+const ATTRIBUTES: &str = r#"
+[__AttributeUsage(_AttributeTargets.Struct)]
+struct GpuStructAttribute {
+    int version;
+    string label;
+};
+
+[GpuStruct(2, "particle")]
+struct Particle {
+    float3 position;
+    float3 velocity;
+    float lifetime[4];
+};
+
+RWStructuredBuffer<Particle> particles;
+
+[shader("compute")]
+[numthreads(64, 1, 1)]
+void main(uint3 id: SV_DispatchThreadID) {
+    particles[id.x].position += particles[id.x].velocity;
+}
+"#;
+
+#[test]
+fn struct_fields_and_user_attributes() {
+    let session = spirv_session();
+    let program = link(&session, "attributes", ATTRIBUTES);
+    let shader = program.layout(0).unwrap();
+
+    let ty = shader.find_type_by_name("Particle").unwrap();
+    assert_eq!(ty.name(), Some("Particle"));
+    assert_eq!(ty.kind(), slang::TypeKind::Struct);
+
+    let particle = ty.as_struct().unwrap();
+    let fields: Vec<_> = particle.fields().map(|f| f.name().unwrap()).collect();
+    assert_eq!(fields, ["position", "velocity", "lifetime"]);
+
+    let attribute = particle.find_user_attribute_by_name("GpuStruct").unwrap();
+    assert_eq!(attribute.name(), Some("GpuStruct"));
+    assert_eq!(attribute.argument_count(), 2);
+    assert_eq!(attribute.argument_value_int(0), Some(2));
+    assert_eq!(ty.user_attributes().len(), 1);
+
+    assert!(ty.as_array().is_none());
+    assert!(particle.find_user_attribute_by_name("Missing").is_none());
+}
+
+const MATERIAL: &str = r#"
+[__AttributeUsage(_AttributeTargets.Var)]
+struct RangeAttribute {
+    float min;
+    float max;
+};
+
+[__AttributeUsage(_AttributeTargets.Var)]
+struct ColorAttribute {};
+
+[__AttributeUsage(_AttributeTargets.Function)]
+struct TooltipAttribute {
+    string text;
+};
+
+struct Material {
+    [Range(0.0, 1.0)]
+    float roughness;
+    [Color]
+    float3 albedo;
+    uint flags;
+};
+
+ConstantBuffer<Material> material;
+RWTexture2D<float4> output;
+
+[Tooltip("shades a pixel")]
+float4 shade(float2 uv) {
+    return float4(material.albedo * uv.x, material.roughness);
+}
+
+[shader("compute")]
+[numthreads(8, 8, 1)]
+void main(uint3 id: SV_DispatchThreadID) {
+    output[id.xy] = shade(float2(id.xy));
+}
+"#;
+
+#[test]
+fn typed_user_attribute_arguments() {
+    use slang::reflect::UserAttributeArgument as A;
+
+    let session = spirv_session();
+    let program = link(&session, "material", MATERIAL);
+    let shader = program.layout(0).unwrap();
+
+    let material = shader.find_type_by_name("Material").unwrap();
+    let fields: Vec<_> = material.fields().collect();
+
+    let range = fields[0].find_user_attribute_by_name("Range").unwrap();
+    assert_eq!(
+        range.arguments().collect::<Vec<_>>(),
+        [A::Float(0.0), A::Float(1.0)]
+    );
+    assert_eq!(range.argument(2), None);
+
+    let color = fields[1].find_user_attribute_by_name("Color").unwrap();
+    assert_eq!(color.argument_count(), 0);
+
+    assert_eq!(fields[2].user_attributes().len(), 0);
+
+    let shade = shader.find_function_by_name("shade").unwrap();
+    let tooltip = shade.find_user_attribute_by_name("Tooltip").unwrap();
+    assert_eq!(tooltip.argument(0), Some(A::String("shades a pixel")));
+}
+
+const FUNCTIONS: &str = r#"
+[__AttributeUsage(_AttributeTargets.Function)]
+struct MaxDispatchGridAttribute {
+    int x;
+    int y;
+    int z;
+};
+
+RWStructuredBuffer<float> output;
+
+float scale(float value) { return value * 2.0; }
+float scale(float value, float factor) { return value * factor; }
+
+void accumulate(inout float total, float value) { total += value; }
+
+[shader("compute")]
+[numthreads(32, 1, 1)]
+[MaxDispatchGrid(16, 1, 1)]
+void main(uint3 id: SV_DispatchThreadID, uniform float bias) {
+    float total = bias;
+    accumulate(total, scale(float(id.x)) + scale(1.0, 3.0));
+    output[id.x] = total;
+}
+"#;
+
+#[test]
+fn function_reflection() {
+    let session = plain_session();
+    let module = load(&session, "functions", FUNCTIONS);
+
+    // Available straight from the unlinked entry point.
+    let entry_point = module.find_entry_point_by_name("main").unwrap();
+    let function = entry_point.function().unwrap();
+    assert_eq!(function.name(), Some("main"));
+    assert_eq!(
+        function.result_type().map(|ty| ty.kind()),
+        Some(slang::TypeKind::Scalar)
+    );
+
+    let parameters: Vec<_> = function.parameters().map(|p| p.name().unwrap()).collect();
+    assert_eq!(parameters, ["id", "bias"]);
+
+    let grid = function
+        .find_user_attribute_by_name("MaxDispatchGrid")
+        .unwrap();
+    assert_eq!(grid.argument_count(), 3);
+    assert_eq!(grid.argument_value_int(0), Some(16));
+    assert!(function.generic_container().is_none());
+    assert!(!function.is_overloaded());
+
+    let session = spirv_session();
+    let program = link(&session, "functions", FUNCTIONS);
+    let shader = program.layout(0).unwrap();
+    let reflected = shader.entry_points().next().unwrap().function().unwrap();
+    assert_eq!(reflected.name(), Some("main"));
+
+    let scale = shader.find_function_by_name("scale").unwrap();
+    assert!(scale.is_overloaded());
+    let arities: Vec<_> = scale.overloads().map(|f| f.parameter_count()).collect();
+    assert_eq!(arities.len(), 2);
+    assert!(arities.contains(&1) && arities.contains(&2));
+
+    let accumulate = shader.find_function_by_name("accumulate").unwrap();
+    let total = accumulate.parameter_by_index(0).unwrap();
+    assert!(total.has_modifier(slang::ModifierId::Inout));
+    assert!(
+        !accumulate
+            .parameter_by_index(1)
+            .unwrap()
+            .has_modifier(slang::ModifierId::Inout)
+    );
+}
+
+const VERTEX: &str = r#"
+struct VertexInput {
+    float3 position : POSITION;
+    float2 uv : TEXCOORD0;
+};
+
+struct VertexOutput {
+    float4 position : SV_Position;
+    float2 uv : TEXCOORD0;
+};
+
+[shader("vertex")]
+VertexOutput main(VertexInput input, uniform float4x4 view_projection, uniform float scale) {
+    VertexOutput output;
+    output.position = mul(view_projection, float4(input.position * scale, 1.0));
+    output.uv = input.uv;
+    return output;
+}
+"#;
+
+#[test]
+fn entry_point_parameters() {
+    let session = spirv_session();
+    let program = link(&session, "vertex", VERTEX);
+    let shader = program.layout(0).unwrap();
+    let entry_point = shader.entry_points().next().unwrap();
+
+    assert_eq!(entry_point.stage(), slang::Stage::Vertex);
+    assert_eq!(entry_point.name_override(), None);
+
+    let parameters: Vec<_> = entry_point.parameters().collect();
+    let names: Vec<_> = parameters.iter().map(|p| p.name().unwrap()).collect();
+    assert_eq!(names, ["input", "view_projection", "scale"]);
+
+    // Varying input, consumed as vertex attributes.
+    let input = parameters[0];
+    assert_eq!(input.stage(), slang::Stage::Vertex);
+    assert!(
+        input
+            .categories()
+            .any(|c| c == slang::ParameterCategory::VaryingInput)
+    );
+
+    // Uniform parameters are packed into the entry point's default constant buffer, which ends
+    // up as push constants on SPIR-V.
+    assert!(entry_point.has_default_constant_buffer());
+    for uniform in &parameters[1..] {
+        assert_eq!(
+            uniform.categories().collect::<Vec<_>>(),
+            [slang::ParameterCategory::Uniform]
+        );
+    }
+    assert_eq!(parameters[1].offset(slang::ParameterCategory::Uniform), 0);
+    assert_eq!(parameters[2].offset(slang::ParameterCategory::Uniform), 64);
+}
+
+const BINDINGS: &str = r#"
+struct Tint {
+    float4 color;
+};
+
+struct Material {
+    Texture2D albedo;
+    SamplerState sampler;
+    ConstantBuffer<Tint> tint;
+};
+
+ParameterBlock<Material> material;
+Texture2D textures[];
+RWStructuredBuffer<float4> output;
+
+[shader("compute")]
+[numthreads(1, 1, 1)]
+void main(uint3 id: SV_DispatchThreadID) {
+    float4 albedo = material.albedo.SampleLevel(material.sampler, float2(0.5), 0);
+    output[id.x] = albedo * material.tint.color + textures[id.x].Load(int3(0));
+}
+"#;
+
+#[test]
+fn binding_ranges_and_descriptor_sets() {
+    use slang::BindingType;
+    use slang::reflect::{BindingCount, TypeLayoutKind};
+
+    let session = spirv_session();
+    let program = link(&session, "bindings", BINDINGS);
+    let shader = program.layout(0).unwrap();
+    let globals = shader
+        .global_params_var_layout()
+        .unwrap()
+        .type_layout()
+        .unwrap();
+
+    let range_of = |name: &str| {
+        globals
+            .binding_ranges()
+            .find(|r| r.leaf_variable().and_then(|v| v.name()) == Some(name))
+            .unwrap()
+    };
+
+    // The parameter block is a single binding that owns its own descriptor set.
+    let block = range_of("material");
+    assert_eq!(block.binding_type(), BindingType::ParameterBlock);
+    assert_eq!(block.binding_count(), BindingCount::Finite(1));
+
+    // Unbounded arrays report `BindingCount::Unbounded` instead of `UNBOUNDED_SIZE`.
+    let textures = range_of("textures");
+    assert_eq!(textures.binding_type(), BindingType::Texture);
+    assert_eq!(textures.binding_count(), BindingCount::Unbounded);
+    assert_eq!(textures.binding_count().finite(), None);
+    assert!(textures.descriptor_range_count() > 0);
+
+    let set = globals
+        .descriptor_set_by_index(textures.descriptor_set_index())
+        .unwrap();
+    let range = set
+        .descriptor_range_by_index(textures.first_descriptor_range_index())
+        .unwrap();
+    assert_eq!(range.binding_type, BindingType::Texture);
+    assert_eq!(range.descriptor_count, BindingCount::Unbounded);
+
+    // The parameter block is the only sub-object at global scope and lives in a space of its own.
+    let sub_objects: Vec<_> = globals.sub_object_ranges().collect();
+    assert_eq!(sub_objects.len(), 1);
+    assert_eq!(sub_objects[0].binding_range_index(), block.index());
+    assert_eq!(
+        sub_objects[0].binding_range().unwrap().binding_type(),
+        BindingType::ParameterBlock
+    );
+    let material = shader
+        .parameters()
+        .find(|p| p.name() == Some("material"))
+        .unwrap();
+    assert_eq!(
+        sub_objects[0].space_offset(),
+        material.offset(slang::ParameterCategory::SubElementRegisterSpace)
+    );
+    assert_ne!(sub_objects[0].space_offset(), set.space_offset());
+
+    // Inside the block, the texture, sampler and constant buffer share a single set.
+    let Some(TypeLayoutKind::SingleElementContainer(container, _)) = material.kind() else {
+        panic!("expected a parameter block layout");
+    };
+    let element = container.element_type_layout().unwrap();
+
+    let ranges: Vec<_> = element.binding_ranges().collect();
+    assert_eq!(
+        ranges.iter().map(|r| r.binding_type()).collect::<Vec<_>>(),
+        [
+            BindingType::Texture,
+            BindingType::Sampler,
+            BindingType::ConstantBuffer
+        ]
+    );
+    assert_eq!(
+        ranges
+            .iter()
+            .map(|r| r.leaf_variable().unwrap().name())
+            .collect::<Vec<_>>(),
+        [Some("albedo"), Some("sampler"), Some("tint")]
+    );
+    for range in &ranges {
+        assert_eq!(range.binding_count(), BindingCount::Finite(1));
+        assert_eq!(range.descriptor_set_index(), 0);
+        assert!(!range.is_specializable());
+    }
+
+    assert_eq!(element.descriptor_set_count(), 1);
+    let set = element.descriptor_set_by_index(0).unwrap();
+    assert_eq!(set.space_offset(), 0);
+    assert_eq!(
+        set.descriptor_ranges()
+            .map(|r| (r.binding_type, r.descriptor_count.finite()))
+            .collect::<Vec<_>>(),
+        [
+            (BindingType::Texture, Some(1)),
+            (BindingType::Sampler, Some(1)),
+            (BindingType::ConstantBuffer, Some(1))
+        ]
+    );
+
+    // The nested constant buffer is a sub-object of the block, in the block's own space.
+    let sub_objects: Vec<_> = element.sub_object_ranges().collect();
+    assert_eq!(sub_objects.len(), 1);
+    assert_eq!(sub_objects[0].binding_range_index(), 2);
+    assert_eq!(sub_objects[0].space_offset(), 0);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn snapshot_round_trip() {
+    use slang::reflect::{BindingCount, ReflectionSnapshot};
+
+    let session = spirv_session();
+    let program = link(&session, "snapshot", BINDINGS);
+    let shader = program.layout(0).unwrap();
+    let snapshot = ReflectionSnapshot::new(shader);
+
+    // Parameter offsets and spaces match the live reflection, category by category.
+    let global_params = snapshot.global_params.as_ref().unwrap();
+    let globals = global_params.type_layout.as_ref().unwrap();
+    assert_eq!(globals.fields.len(), shader.parameter_count() as usize);
+    for (parameter, field) in shader.parameters().zip(&globals.fields) {
+        assert_eq!(parameter.name(), field.name.as_deref());
+        assert_eq!(parameter.category_count() as usize, field.offsets.len());
+        for category in parameter.categories() {
+            let offset = field.offset(category).unwrap();
+            assert_eq!(offset.offset, parameter.offset(category));
+            assert_eq!(
+                offset.space,
+                parameter.binding_space_with_category(category)
+            );
+        }
+    }
+
+    // Binding ranges, including the unbounded texture array, are copied as-is.
+    let live = shader
+        .global_params_var_layout()
+        .unwrap()
+        .type_layout()
+        .unwrap();
+    assert_eq!(globals.binding_ranges.len(), live.binding_range_count());
+    for (range, snapshot) in live.binding_ranges().zip(&globals.binding_ranges) {
+        assert_eq!(snapshot.binding_type, range.binding_type());
+        assert_eq!(snapshot.binding_count, range.binding_count());
+        assert_eq!(
+            snapshot.leaf_variable_name.as_deref(),
+            range.leaf_variable().and_then(|v| v.name())
+        );
+        assert_eq!(snapshot.descriptor_set_index, range.descriptor_set_index());
+    }
+    assert!(
+        globals
+            .binding_ranges
+            .iter()
+            .any(|r| r.binding_count == BindingCount::Unbounded)
+    );
+    assert_eq!(globals.descriptor_sets.len(), live.descriptor_set_count());
+    assert_eq!(
+        globals.sub_object_ranges.len(),
+        live.sub_object_range_count()
+    );
+
+    let entry_point = snapshot.find_entry_point_by_name("main").unwrap();
+    assert_eq!(entry_point.stage, slang::Stage::Compute);
+    assert_eq!(entry_point.compute_thread_group_size, [1, 1, 1]);
+
+    let json = snapshot.to_json().unwrap();
+    assert_eq!(ReflectionSnapshot::from_json(&json).unwrap(), snapshot);
+
+    let bytes = snapshot.to_bytes().unwrap();
+    assert_eq!(ReflectionSnapshot::from_bytes(&bytes).unwrap(), snapshot);
+}
+
+const OUTLINE: &str = r#"
+struct Light {
+    float3 direction;
+    float intensity;
+};
+
+static const uint MAX_LIGHTS = 4;
+
+float attenuate(Light light, float distance) {
+    return light.intensity / (distance * distance);
+}
+
+T identity<T>(T value) { return value; }
+
+// Not referenced from any entry point.
+float unused(float x) { return x; }
+"#;
+
+#[test]
+fn module_decl_tree() {
+    use slang::DeclKind;
+
+    let session = plain_session();
+    let module = load(&session, "outline", OUTLINE);
+
+    let root = module.module_reflection().unwrap();
+    assert_eq!(root.kind(), DeclKind::Module);
+    assert!(root.parent().is_none());
+
+    let find = |name: &str| root.children().find(|d| d.name() == Some(name)).unwrap();
+
+    let light = find("Light");
+    assert_eq!(light.kind(), DeclKind::Struct);
+    let fields: Vec<_> = light
+        .children()
+        .filter(|d| d.kind() == DeclKind::Variable)
+        .map(|d| d.name().unwrap())
+        .collect();
+    assert_eq!(fields, ["direction", "intensity"]);
+    assert_eq!(light.ty().and_then(|ty| ty.name()), Some("Light"));
+    assert_eq!(
+        light.children().next().unwrap().parent().unwrap().name(),
+        Some("Light")
+    );
+
+    let attenuate = find("attenuate").as_function().unwrap();
+    assert_eq!(attenuate.parameter_count(), 2);
+    assert!(find("unused").as_function().is_some());
+
+    let max_lights = find("MAX_LIGHTS");
+    assert!(max_lights.as_variable().is_some());
+    assert!(max_lights.has_modifier(slang::ModifierId::Static));
+    assert!(max_lights.as_function().is_none());
+
+    let identity = root
+        .children()
+        .find(|d| d.kind() == DeclKind::Generic)
+        .unwrap();
+    assert_eq!(identity.as_generic().unwrap().name(), Some("identity"));
+}
+
+const GENERICS: &str = r#"
+interface IShape {
+    float area();
+};
+
+struct Square : IShape {
+    float side;
+    float area() { return side * side; }
+};
+
+struct Buffer<T : IShape, let N : int> {
+    T shapes[N];
+};
+
+[shader("compute")]
+[numthreads(64, 1, 1)]
+void main<T : IShape, let N : int>(uniform Buffer<T, N> input, uniform RWStructuredBuffer<float> output) {
+    output[0] = input.shapes[0].area();
+}
+"#;
+
+#[test]
+fn generic_specialization() {
+    use slang::reflect::{GenericArg, SpecializationParameter};
+
+    let session = plain_session();
+    let program = compose(&session, "generics", GENERICS);
+    let shader = program.layout(0).unwrap();
+
+    let params: Vec<_> = shader.specialization_parameters().collect();
+    let names: Vec<_> = params.iter().map(|p| p.name().unwrap()).collect();
+    assert_eq!(names, ["T", "N"]);
+    assert!(matches!(
+        params[0],
+        SpecializationParameter::EntryPointType(..)
+    ));
+    assert!(matches!(
+        params[1],
+        SpecializationParameter::EntryPointValue(..)
+    ));
+
+    let constraints = params[0].constraints();
+    assert_eq!(constraints.len(), 1);
+    assert_eq!(constraints[0].name(), Some("IShape"));
+    assert!(params[1].constraints().is_empty());
+
+    let square = shader.find_type_by_name("Square").unwrap();
+
+    let generic = match params[0] {
+        SpecializationParameter::EntryPointType(generic, _) => generic,
+        _ => unreachable!(),
+    };
+    assert_eq!(generic.type_parameter_count(), 1);
+    assert_eq!(generic.value_parameter_count(), 1);
+
+    let specialized = shader
+        .specialize_generic(generic, &[GenericArg::Type(square), GenericArg::Int(4)])
+        .unwrap();
+    let t = specialized.type_parameter_by_index(0).unwrap();
+    let n = specialized.value_parameter_by_index(0).unwrap();
+    assert_eq!(
+        specialized.concrete_type(t).and_then(|ty| ty.name()),
+        Some("Square")
+    );
+    assert_eq!(specialized.concrete_int_val(n), 4);
+
+    let buffer = shader.find_type_by_name("Buffer<Square, 4>").unwrap();
+    assert_eq!(buffer.name(), Some("Buffer"));
+    assert!(buffer.full_name().unwrap().contains("Square"));
+}
+
+const EXISTENTIALS: &str = r#"
+interface ILight {
+    float intensity();
+};
+
+struct Sun : ILight {
+    float intensity() { return 1.0; }
+};
+
+uniform ILight light;
+
+[shader("compute")]
+[numthreads(1, 1, 1)]
+void main<let N : int>(uniform ILight fill, uniform RWStructuredBuffer<float> output) {
+    output[0] = light.intensity() + fill.intensity() * N;
+}
+"#;
+
+#[test]
+fn existential_specialization_parameters() {
+    use slang::SpecializeArgument;
+    use slang::reflect::SpecializationParameter;
+
+    let session = spirv_session();
+    let program = compose(&session, "existentials", EXISTENTIALS);
+    let shader = program.layout(0).unwrap();
+
+    let params: Vec<_> = shader.specialization_parameters().collect();
+    assert_eq!(params.len(), program.specialization_param_count());
+
+    let names: Vec<_> = params.iter().map(|p| p.name().unwrap()).collect();
+    assert_eq!(names, ["light", "N", "fill"]);
+    assert!(matches!(
+        params[0],
+        SpecializationParameter::Existential(..)
+    ));
+    assert!(matches!(
+        params[1],
+        SpecializationParameter::EntryPointValue(..)
+    ));
+    assert!(matches!(
+        params[2],
+        SpecializationParameter::Existential(..)
+    ));
+    assert_eq!(params[0].constraints()[0].name(), Some("ILight"));
+
+    let sun = shader.find_type_by_name("Sun").unwrap();
+    let args: Vec<_> = params
+        .iter()
+        .map(|param| match param {
+            SpecializationParameter::EntryPointValue(..) => SpecializeArgument::Expr("2"),
+            _ => SpecializeArgument::Type(sun),
+        })
+        .collect();
+    program.specialize(&args).unwrap().link().unwrap();
+}
+
+const LIGHTS: &str = r#"
+interface ILight {
+    float intensity();
+};
+
+struct Sun : ILight {
+    float intensity() { return 1.0; }
+};
+"#;
+
+const SCENE: &str = r#"
+import lights;
+
+type_param T : ILight;
+
+uniform T key_light;
+uniform ILight fill;
+
+[shader("compute")]
+[numthreads(1, 1, 1)]
+void main(uniform RWStructuredBuffer<float> output) {
+    output[0] = key_light.intensity() + fill.intensity();
+}
+"#;
+
+#[test]
+fn multi_module_specialization_parameters() {
+    use slang::SpecializeArgument;
+    use slang::reflect::SpecializationParameter;
+
+    let session = spirv_session();
+    let lights = load(&session, "lights", LIGHTS);
+    let scene = load(&session, "scene", SCENE);
+    let entry_point = scene.find_entry_point_by_name("main").unwrap();
+
+    // Only `scene` has global specialization parameters, so the order still matches.
+    let program = session
+        .create_composite_component_type(&[lights.into(), scene.into(), entry_point.into()])
+        .unwrap();
+    let shader = program.layout(0).unwrap();
+
+    let params: Vec<_> = shader.specialization_parameters().collect();
+    assert_eq!(params.len(), program.specialization_param_count());
+    assert!(matches!(params[0], SpecializationParameter::Type(_)));
+    assert!(matches!(
+        params[1],
+        SpecializationParameter::Existential(..)
+    ));
+    assert_eq!(format!("{:?}", params[0]), r#"Type(Some("T"))"#);
+    assert_eq!(format!("{:?}", params[1]), r#"Existential(Some("fill"))"#);
+
+    let sun = shader.find_type_by_name("Sun").unwrap();
+    program
+        .specialize(&[SpecializeArgument::Type(sun), SpecializeArgument::Type(sun)])
+        .unwrap()
+        .link()
+        .unwrap();
+}
+
+const PARTICLES: &str = r#"
+struct Particle {
+    float3 position;
+    float lifetime;
+    float3 velocity;
+};
+
+struct Pair<T> {
+    T first;
+    T second;
+};
+"#;
+
+#[test]
+fn session_type_queries() {
+    use slang::{ContainerType, LayoutRules, ParameterCategory};
+
+    let session = spirv_session();
+    let module = load(&session, "particles", PARTICLES);
+    let shader = module.layout(0).unwrap();
+
+    let particle = shader.find_type_by_name("Particle").unwrap();
+    let layout = session
+        .type_layout(particle, 0, LayoutRules::DefaultStructuredBuffer)
+        .unwrap();
+    assert_eq!(layout.size(ParameterCategory::Uniform), 32);
+
+    let buffer = session
+        .container_type(particle, ContainerType::StructuredBuffer)
+        .unwrap();
+    assert_eq!(buffer.kind(), slang::TypeKind::Resource);
+    assert_eq!(
+        buffer
+            .as_resource()
+            .unwrap()
+            .result_type()
+            .and_then(|ty| ty.name()),
+        Some("Particle")
+    );
+
+    let pair = shader.find_type_by_name("Pair").unwrap();
+    let specialized = session
+        .specialize_type(pair, &[slang::SpecializeArgument::Type(particle)])
+        .unwrap();
+    let layout = session
+        .type_layout(specialized, 0, LayoutRules::DefaultStructuredBuffer)
+        .unwrap();
+    assert_eq!(layout.size(ParameterCategory::Uniform), 64);
+}
+
+const MATERIALS: &str = r#"
+interface IMaterial {
+    float3 shade(float3 normal);
+};
+
+struct Lambert : IMaterial {
+    float3 albedo;
+    float3 shade(float3 normal) { return albedo * saturate(normal.y); }
+};
+
+struct Emissive : IMaterial {
+    float3 color;
+    float3 shade(float3 normal) { return color; }
+};
+
+uniform IMaterial material;
+RWStructuredBuffer<float3> output;
+
+[shader("compute")]
+[numthreads(64, 1, 1)]
+void main(uint3 id: SV_DispatchThreadID) {
+    output[id.x] = material.shade(float3(0.0, 1.0, 0.0));
+}
+"#;
+
+#[test]
+fn type_conformances() {
+    let session = spirv_session();
+    let module = load(&session, "materials", MATERIALS);
+    let shader = module.layout(0).unwrap();
+
+    let interface = shader.find_type_by_name("IMaterial").unwrap();
+    let lambert = shader.find_type_by_name("Lambert").unwrap();
+    let emissive = shader.find_type_by_name("Emissive").unwrap();
+
+    let lambert_conformance = session
+        .create_type_conformance(lambert, interface, None)
+        .unwrap();
+    let emissive_conformance = session
+        .create_type_conformance(emissive, interface, Some(7))
+        .unwrap();
+
+    let entry_point = module.find_entry_point_by_name("main").unwrap();
+    let program = session
+        .create_composite_component_type(&[
+            module.clone().into(),
+            entry_point.into(),
+            lambert_conformance.into(),
+            emissive_conformance.into(),
+        ])
+        .unwrap()
+        .link()
+        .unwrap();
+    program.entry_point_code(0, 0).unwrap();
+
+    assert_eq!(
+        session
+            .type_conformance_witness_sequential_id(emissive, interface)
+            .unwrap(),
+        7
+    );
+    assert!(
+        session
+            .type_conformance_witness_mangled_name(lambert, interface)
+            .unwrap()
+            .contains("Lambert")
+    );
+    assert!(!session.type_rtti_mangled_name(lambert).unwrap().is_empty());
+    session
+        .dynamic_object_rtti_bytes(lambert, interface)
+        .unwrap();
+
+    // A type that doesn't conform to the interface is rejected.
+    let float = shader.find_type_by_name("float").unwrap();
+    assert!(
+        session
+            .create_type_conformance(float, interface, None)
+            .is_err()
+    );
+}